@@ -1,7 +1,6 @@
 #![allow(clippy::let_and_return)]
 #![deny(
     unused_variables,
-    mutable_borrow_reservation_conflict,
     dead_code,
     unused_must_use,
     unused_imports
@@ -9,11 +8,15 @@
 
 extern crate proc_macro;
 
-use heck::ToSnakeCase;
+use heck::{ToShoutySnakeCase, ToSnakeCase};
 use proc_macro2::TokenStream;
 use proc_macro_error::*;
 use quote::*;
-use syn::{spanned::Spanned, *};
+use syn::{ext::IdentExt, parse::Parser, spanned::Spanned, *};
+
+use crate::options::{FieldOptions, StructOptions, UnknownArgs, WhenAbsent};
+
+mod options;
 
 /// See root module docs for more info.
 #[proc_macro_derive(FromAttributes, attributes(bae))]
@@ -23,16 +26,65 @@ pub fn from_attributes(input: proc_macro::TokenStream) -> proc_macro::TokenStrea
     FromAttributes::new(item).expand().into()
 }
 
+/// The keywords that can't name a `syn::custom_keyword!`, all of which have a `syn::Token!`.
+const RUST_KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "final", "fn", "for", "if", "impl", "in", "let", "loop",
+    "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return", "Self",
+    "self", "static", "struct", "super", "trait", "try", "type", "typeof", "unsafe", "unsized",
+    "use", "virtual", "where", "while", "yield",
+];
+
 #[derive(Debug)]
 struct FromAttributes {
     item: ItemStruct,
+    options: StructOptions,
+    fields: Vec<BaeField>,
     tokens: TokenStream,
 }
 
+#[derive(Debug)]
+struct BaeField {
+    field: Field,
+    options: FieldOptions,
+    /// The key of the argument, from `rename` or the field's name with the field's or else the
+    /// struct's `prefix` and `suffix`.
+    key: LitStr,
+}
+
 impl FromAttributes {
     fn new(item: ItemStruct) -> Self {
+        let options = StructOptions::from_attrs(&item.attrs);
+        let fields = item
+            .fields
+            .iter()
+            .map(|field| {
+                let field_options = FieldOptions::from_attrs(&field.attrs);
+                let key = field_options.rename.clone().unwrap_or_else(|| {
+                    let name = get_field_name(field).unraw().to_string();
+                    let affix = |field: &Option<LitStr>, item: &Option<LitStr>| {
+                        field.as_ref().or(item.as_ref()).map(LitStr::value)
+                    };
+                    let key = format!(
+                        "{}{}{}",
+                        affix(&field_options.prefix, &options.prefix).unwrap_or_default(),
+                        name,
+                        affix(&field_options.suffix, &options.suffix).unwrap_or_default()
+                    );
+                    LitStr::new(&key, field.span())
+                });
+                BaeField {
+                    field: field.clone(),
+                    options: field_options,
+                    key,
+                }
+            })
+            .collect();
+
         Self {
             item,
+            options,
+            fields,
             tokens: TokenStream::new(),
         }
     }
@@ -40,12 +92,30 @@ impl FromAttributes {
     fn expand(mut self) -> TokenStream {
         self.expand_from_attributes_method();
         self.expand_parse_impl();
+        self.expand_inherent_methods();
+        self.expand_merge_method();
+        self.expand_try_from_impl();
+        self.expand_into_inner_method();
+        self.expand_to_tokens_impl();
+        self.expand_present_args_method();
+
+        // The generated code uses the `syn` and `proc_macro2` this crate was built with, which
+        // are the ones its types come from, rather than whichever the user's crate depends on
+        let tokens = self.tokens;
+        let tokens = quote! {
+            const _: () = {
+                #[allow(unused_imports)]
+                use ::better_bae::__private::{proc_macro2, syn};
+
+                #tokens
+            };
+        };
 
         if std::env::var("BAE_DEBUG").is_ok() {
-            eprintln!("{}", self.tokens);
+            eprintln!("{}", tokens);
         }
 
-        self.tokens
+        tokens
     }
 
     fn struct_name(&self) -> &Ident {
@@ -53,135 +123,2128 @@ impl FromAttributes {
     }
 
     fn attr_name(&self) -> LitStr {
+        // Only the ident, generic parameters aren't part of the name
         let struct_name = self.struct_name();
-        let mut name = struct_name.to_string().to_snake_case();
-        for attr in &self.item.attrs {
-            if attr.path.is_ident("bae") {
-                if let Ok(lit) = attr.parse_args::<syn::LitStr>() {
-                    name = lit.value();
-                }
-            }
-        }
+        let name = match (&self.options.name_prefix, &self.options.name) {
+            (Some(prefix), _) => format!("{}*", prefix.value()),
+            (None, Some(lit)) => lit.value(),
+            (None, None) => struct_name.to_string().to_snake_case(),
+        };
         LitStr::new(&name, struct_name.span())
     }
 
+    /// Whether the attribute takes no arguments, as in `#[my_marker]`.
+    fn is_marker(&self) -> bool {
+        self.argument_fields().next().is_none()
+            && self.rest_field().is_none()
+            && self.flatten_field().is_none()
+            && self.switches_field().is_none()
+    }
+
+    /// The `repeated` fields with a `count_range`, checked once all arguments are parsed.
+    fn repeated_count_fields(&self) -> impl Iterator<Item = &BaeField> {
+        self.argument_fields()
+            .filter(|field| field.options.repeated && field.options.count_bounds().is_some())
+    }
+
+    /// Where a greedily parsed argument value ends.
+    fn value_end(&self) -> ValueEnd {
+        if self.options.after_parse.is_some() {
+            ValueEnd::AfterParse
+        } else {
+            ValueEnd::Argument
+        }
+    }
+
+    /// The field marked `#[bae(rest)]`, which collects the arguments no other field matches.
+    fn rest_field(&self) -> Option<&BaeField> {
+        let mut rest = self.fields.iter().filter(|field| field.options.rest);
+        let field = rest.next()?;
+        if let Some(other) = rest.next() {
+            abort!(other.field.span(), "only one field can be `#[bae(rest)]`");
+        }
+        if self.options.string_keys {
+            abort!(
+                field.field.span(),
+                "`#[bae(rest)]` can't be used with `#[bae(string_keys)]`"
+            );
+        }
+        Some(field)
+    }
+
+    /// The field marked `#[bae(flatten)]`, which parses the arguments no other field matches as
+    /// another attribute struct.
+    fn flatten_field(&self) -> Option<&BaeField> {
+        let mut flatten = self.fields.iter().filter(|field| field.options.flatten);
+        let field = flatten.next()?;
+        if let Some(other) = flatten.next() {
+            abort!(other.field.span(), "only one field can be `#[bae(flatten)]`");
+        }
+        if self.rest_field().is_some() {
+            abort!(
+                field.field.span(),
+                "`#[bae(flatten)]` can't be used with `#[bae(rest)]`"
+            );
+        }
+        Some(field)
+    }
+
+    /// The field marked `#[bae(catch_all_switches)]`, which collects the flags no other field
+    /// matches.
+    fn switches_field(&self) -> Option<&BaeField> {
+        let mut switches = self
+            .fields
+            .iter()
+            .filter(|field| field.options.catch_all_switches);
+        let field = switches.next()?;
+        if let Some(other) = switches.next() {
+            abort!(
+                other.field.span(),
+                "only one field can be `#[bae(catch_all_switches)]`"
+            );
+        }
+        let conflicting = [
+            (self.options.string_keys, "string_keys"),
+            (self.flatten_field().is_some(), "flatten"),
+        ];
+        if let Some((_, option)) = conflicting.iter().find(|(conflicts, _)| *conflicts) {
+            abort!(
+                field.field.span(),
+                "`#[bae(catch_all_switches)]` can't be used with `#[bae({})]`",
+                option
+            );
+        }
+        Some(field)
+    }
+
+    /// The field marked `#[bae(matched_suffix)]`, which requires `name_prefix`.
+    fn matched_suffix_field(&self) -> Option<&BaeField> {
+        let field = self
+            .fields
+            .iter()
+            .find(|field| field.options.matched_suffix)?;
+        if self.options.name_prefix.is_none() {
+            abort!(
+                field.field.span(),
+                "`matched_suffix` requires `#[bae(name_prefix = \"...\")]` on the struct"
+            );
+        }
+        Some(field)
+    }
+
+    /// Fields that are parsed from the attribute's arguments.
+    fn argument_fields(&self) -> impl Iterator<Item = &BaeField> {
+        self.fields
+            .iter()
+            .filter(|field| field.options.is_argument())
+    }
+
     fn expand_from_attributes_method(&mut self) {
         let struct_name = self.struct_name();
+        let (impl_generics, ty_generics, where_clause) = self.item.generics.split_for_impl();
         let attr_name = self.attr_name().value();
 
-        let code = quote! {
-            impl ::better_bae::TryFromAttributes for #struct_name {
-                fn attr_name() -> &'static str {
-                    #attr_name
+        let capture_meta = self
+            .fields
+            .iter()
+            .filter(|field| field.options.capture_meta)
+            .map(|field| {
+                let field_name = get_field_name(&field.field);
+                quote! {
+                    parsed.#field_name = ::better_bae::AttrMeta {
+                        path: attr.path.clone(),
+                        style: attr.style,
+                    };
+                }
+            });
+
+        let matched_suffix = self.matched_suffix_field().map(|field| {
+            let field_name = get_field_name(&field.field);
+            let prefix = &self.options.name_prefix;
+            quote! {
+                parsed.#field_name = ::better_bae::__private::attr_path_suffix(&attr.path, #prefix)
+                    .unwrap_or_default();
+            }
+        });
+        let is_attr = self.options.name_prefix.as_ref().map(|prefix| {
+            quote! {
+                fn is_attr(attr: &syn::Attribute) -> bool {
+                    ::better_bae::__private::attr_path_suffix(&attr.path, #prefix).is_some()
+                }
+            }
+        });
+
+        let parse_args = self.parse_args();
+        let with_examples = self.with_examples();
+        let accepts_key = self.accepts_key();
+
+        let parse_attr = if self.options.skip_if_no_args {
+            quote! {
+                if attr.tokens.is_empty() {
+                    <Self as std::default::Default>::default()
+                } else {
+                    syn::parse2::<Self>(attr.tokens.clone())?
                 }
+            }
+        } else {
+            quote! { syn::parse2::<Self>(attr.tokens.clone())? }
+        };
+
+        let code = quote! {
+            impl #impl_generics ::better_bae::TryFromAttributes for #struct_name #ty_generics #where_clause {
+                const ATTR_NAME: &'static str = #attr_name;
 
-                fn try_from_attributes(attrs: &[::syn::Attribute]) -> ::syn::Result<Option<Self>> {
-                    use ::syn::spanned::Spanned;
+                fn try_from_attributes(attrs: &[syn::Attribute]) -> syn::Result<Option<Self>> {
+                    use syn::spanned::Spanned;
 
                     for attr in attrs {
-                        match attr.path.get_ident() {
-                            Some(ident) if ident == #attr_name => {
-                                return Some(syn::parse2::<Self>(attr.tokens.clone())).transpose()
-                            }
-                            // Ignore other attributes
-                            _ => {},
+                        ::better_bae::__private::count_scanned();
+                        // Ignore other attributes
+                        if <Self as ::better_bae::TryFromAttributes>::is_attr(attr) {
+                            ::better_bae::__private::count_parsed();
+                            #[allow(unused_mut)]
+                            let mut parsed = #parse_attr;
+                            #(#capture_meta)*
+                            #matched_suffix
+                            return Ok(Some(parsed));
                         }
                     }
 
                     Ok(None)
                 }
+
+                #is_attr
+
+                #accepts_key
+
+                #[allow(
+                    unreachable_code,
+                    unused_imports,
+                    unused_variables,
+                    clippy::needless_question_mark
+                )]
+                fn parse_until(
+                    content: syn::parse::ParseStream,
+                    stop: fn(syn::parse::ParseStream) -> bool,
+                ) -> syn::Result<Self> {
+                    let parse = |content: syn::parse::ParseStream| -> syn::Result<Self> { #parse_args };
+                    parse(content)#with_examples
+                }
             }
         };
         self.tokens.extend(code);
     }
 
+    /// The `accepts_key` method, matching the keys of the argument fields. A `rest` or
+    /// `catch_all_switches` field takes any key, so does a `list` struct like the default method.
+    fn accepts_key(&self) -> Option<TokenStream> {
+        if self.options.list || self.rest_field().is_some() || self.switches_field().is_some() {
+            return None;
+        }
+
+        let patterns = self.argument_fields().map(|field| self.key_pattern(field));
+        let key = if self.options.case_insensitive {
+            quote! { ::better_bae::__private::fold_key(key) }
+        } else {
+            quote! { key.to_string() }
+        };
+        let otherwise = match self.flatten_field() {
+            Some(flatten) => {
+                let ty = &flatten.field.ty;
+                let ty = if field_is_optional(&flatten.field) {
+                    inner_type(ty).unwrap_or(ty)
+                } else {
+                    ty
+                };
+                quote! { <#ty as ::better_bae::TryFromAttributes>::accepts_key(key) }
+            }
+            None => quote! { false },
+        };
+        Some(quote! {
+            fn accepts_key(key: &str) -> bool {
+                match &*#key {
+                    #(#patterns => true,)*
+                    _ => #otherwise,
+                }
+            }
+        })
+    }
+
     fn expand_parse_impl(&mut self) {
         let struct_name = self.struct_name();
+        let (impl_generics, ty_generics, where_clause) = self.item.generics.split_for_impl();
+
+        let with_examples = self.with_examples();
+
+        let allow_missing_parens = if self.is_marker() || self.options.allow_missing_parens {
+            quote! {
+                if input.is_empty() {
+                    return <Self as ::better_bae::TryFromAttributes>::parse_args(input);
+                }
+            }
+        } else {
+            let attr_name = self.attr_name().value();
+            let message = format!(
+                "`#[{}]` requires arguments in parentheses, as in `#[{}(...)]`",
+                attr_name, attr_name
+            );
+            quote! {
+                if input.is_empty() {
+                    return syn::Result::Err(syn::Error::new(input.span(), #message))#with_examples;
+                }
+            }
+        };
+
+        let trailing_message = format!(
+            "unexpected tokens after `#[{}(...)]`",
+            self.attr_name().value()
+        );
+
+        let code = quote! {
+            impl #impl_generics syn::parse::Parse for #struct_name #ty_generics #where_clause {
+                fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+                    #allow_missing_parens
+
+                    let content = ::better_bae::__private::parse_delimited(input)#with_examples?;
+                    let parsed = <Self as ::better_bae::TryFromAttributes>::parse_args(&content)?;
+                    if !input.is_empty() {
+                        return syn::Result::Err(input.error(#trailing_message));
+                    }
+                    syn::Result::Ok(parsed)
+                }
+            }
+        };
+        self.tokens.extend(code);
+    }
+
+    /// Maps a `syn::Result` so its errors end with the struct's usage examples, if any.
+    fn with_examples(&self) -> TokenStream {
+        let examples = &self.options.examples;
+        if examples.is_empty() {
+            return quote! {};
+        }
+
+        quote! {
+            .map_err(|err| ::better_bae::__private::with_examples(err, &[#(#examples),*]))
+        }
+    }
+
+    /// The key matched against the parsed key, folded with `case_insensitive`.
+    fn matched_key(&self, field: &BaeField) -> LitStr {
+        let key = field_key(field);
+        if self.options.case_insensitive {
+            LitStr::new(&fold_key(&key.value()), key.span())
+        } else {
+            key
+        }
+    }
+
+    /// The pattern matching a field's key. Fields with `ignore_case` compare the folded key in a
+    /// guard, the others match it exactly.
+    fn key_pattern(&self, field: &BaeField) -> TokenStream {
+        let key = self.matched_key(field);
+        if field.options.ignore_case && !self.options.case_insensitive {
+            let folded = LitStr::new(&fold_key(&key.value()), key.span());
+            quote! { key if ::better_bae::__private::fold_key(key) == #folded }
+        } else {
+            quote! { #key }
+        }
+    }
+
+    fn check_keys(&self) {
+        let mut seen = std::collections::HashSet::new();
+        for field in self.argument_fields() {
+            let key = field_key(field);
+            if !self.options.string_keys && Ident::parse_any.parse_str(&key.value()).is_err() {
+                abort!(
+                    key.span(),
+                    "the key must be an identifier, unless the struct has `#[bae(string_keys)]`"
+                );
+            }
+            if !seen.insert(self.matched_key(field).value()) {
+                abort!(key.span(), "two fields map to key `{}`", key.value());
+            }
+        }
+
+        for field in self.argument_fields().filter(|field| field.options.ignore_case) {
+            let key = field_key(field);
+            let folded = fold_key(&key.value());
+            let other = self.argument_fields().find(|other| {
+                !std::ptr::eq(*other, field) && fold_key(&field_key(other).value()) == folded
+            });
+            if let Some(other) = other {
+                abort!(
+                    field_key(other).span(),
+                    "two fields map to key `{}` with `ignore_case`",
+                    key.value()
+                );
+            }
+        }
+    }
+
+    fn check_defaults(&self) {
+        for field in self.argument_fields() {
+            let options = &field.options;
+            if let Some(default) = &options.parse_quote_default {
+                check_parse_quote_default(&field.field.ty, default);
+            }
+            let mut defaults = options
+                .default
+                .as_ref()
+                .map(|default| ("default", default.span()))
+                .into_iter()
+                .chain(options.default_fn.as_ref().map(|path| ("default_fn", path.span())))
+                .chain(
+                    options
+                        .parse_quote_default
+                        .as_ref()
+                        .map(|default| ("parse_quote_default", default.span())),
+                );
+            let default = match (defaults.next(), defaults.next()) {
+                (Some((_, span)), None) => span,
+                (Some((first, _)), Some((second, span))) => abort!(
+                    span,
+                    "`{}` and `{}` can't be used together",
+                    first,
+                    second
+                ),
+                (None, _) => continue,
+            };
+
+            if field_is_optional(&field.field) {
+                abort!(
+                    default,
+                    "`default` can't be used on `Option` fields, which default to `None`"
+                );
+            }
+            if field.options.required {
+                abort!(default, "`default` can't be used with `required`");
+            }
+            if field.options.when_absent() == Some(WhenAbsent::Error) {
+                abort!(default, "`default` can't be used with `when_absent = \"error\"`");
+            }
+        }
+    }
+
+    /// Whether a missing argument is `Default::default()`, through `when_absent = "default"` or
+    /// the struct's `all_optional`.
+    fn defaults_when_absent(&self, field: &BaeField) -> bool {
+        match field.options.when_absent() {
+            Some(policy) => policy == WhenAbsent::Default,
+            None => self.options.all_optional && !field.options.required,
+        }
+    }
+
+    /// Checks each `when_absent` is compatible with the field's type and other options.
+    fn check_when_absent(&self) {
+        for field in self.argument_fields() {
+            let (policy, span) = match field.options.when_absent {
+                Some(when_absent) => when_absent,
+                None => continue,
+            };
+            if field.options.required {
+                abort!(span, "`when_absent` can't be used with `required`");
+            }
+            let optional = field_is_optional(&field.field);
+            match policy {
+                WhenAbsent::Default if optional => abort!(
+                    span,
+                    "`Option` fields are `None` when absent, use `when_absent = \"skip\"`"
+                ),
+                WhenAbsent::Skip if !optional => abort!(
+                    span,
+                    "`when_absent = \"skip\"` requires an `Option` field, which is left `None`"
+                ),
+                _ => {}
+            }
+        }
+    }
+
+    /// The fields that can't be given together with `field`, through `conflicts_with` on either
+    /// of them.
+    fn conflicting_fields<'a>(&'a self, field: &'a BaeField) -> impl Iterator<Item = &'a BaeField> {
+        let name = get_field_name(&field.field);
+        self.argument_fields().filter(move |other| {
+            let other_name = get_field_name(&other.field);
+            field.options.conflicts_with.iter().any(|conflict| conflict == other_name)
+                || other.options.conflicts_with.iter().any(|conflict| conflict == name)
+        })
+    }
+
+    fn check_conflicts(&self) {
+        for field in &self.fields {
+            for conflict in &field.options.conflicts_with {
+                let exists = self
+                    .argument_fields()
+                    .any(|other| get_field_name(&other.field) == conflict);
+                if !exists {
+                    abort!(conflict.span(), "unknown field `{}`", conflict);
+                }
+            }
+        }
+    }
+
+    /// The options of `::better_bae::__private::parse_arguments`.
+    fn arguments(&self) -> TokenStream {
+        let StructOptions {
+            string_keys,
+            case_insensitive,
+            emit_diagnostics,
+            ..
+        } = self.options;
+        let after_parse = self.options.after_parse.is_some();
+        let unknown = match self.options.unknown {
+            UnknownArgs::Ignore => quote! { Ignore },
+            UnknownArgs::Warn => quote! { Warn },
+            UnknownArgs::Error => quote! { Error },
+        };
+        let keywords = match self.keywords() {
+            Some(keywords) => quote! { std::option::Option::Some(#keywords) },
+            None => quote! { std::option::Option::None },
+        };
+        quote! {
+            ::better_bae::__private::Arguments {
+                string_keys: #string_keys,
+                case_insensitive: #case_insensitive,
+                after_parse: #after_parse,
+                emit_diagnostics: #emit_diagnostics,
+                unknown: ::better_bae::__private::Unknown::#unknown,
+                keywords: #keywords,
+            }
+        }
+    }
+
+    /// With `#[bae(keywords)]`, a function peeking each key as a keyword token, generated with
+    /// `syn::custom_keyword!` or, for Rust keywords, `syn::Token!`. Its lookahead error lists the
+    /// expected keys.
+    fn keywords(&self) -> Option<TokenStream> {
+        if !self.options.keywords {
+            return None;
+        }
+        let conflicting = [
+            (self.options.string_keys, "string_keys"),
+            (self.options.case_insensitive, "case_insensitive"),
+            (self.options.after_parse.is_some(), "after_parse"),
+            (self.rest_field().is_some(), "rest"),
+            (self.flatten_field().is_some(), "flatten"),
+            (self.switches_field().is_some(), "catch_all_switches"),
+        ];
+        if let Some((_, option)) = conflicting.iter().find(|(conflicts, _)| *conflicts) {
+            abort!(
+                self.item.ident,
+                "`#[bae(keywords)]` can't be used with `#[bae({})]`",
+                option
+            );
+        }
+
+        // An unknown key fails the lookahead, so it is always an error
+        if let Some(span) = self.options.unknown_span {
+            if self.options.unknown != UnknownArgs::Error {
+                abort!(
+                    span,
+                    "`#[bae(keywords)]` can't be used with `#[bae(unknown = \"ignore\")]` or \
+                     `#[bae(unknown = \"warn\")]`, unknown keys are always an error"
+                );
+            }
+        }
+
+        let keys = self.argument_fields().map(field_key);
+        let mut definitions = Vec::new();
+        let tokens = self
+            .argument_fields()
+            .map(|field| {
+                let key = field_key(field);
+                if field.options.ignore_case {
+                    abort!(
+                        key.span(),
+                        "`#[bae(ignore_case)]` can't be used with `#[bae(keywords)]`"
+                    );
+                }
+                let ident = Ident::new(&key.value(), key.span());
+                if key.value() == "true" || key.value() == "false" {
+                    abort!(
+                        key.span(),
+                        "`{}` can't be a key with `#[bae(keywords)]`",
+                        key.value()
+                    );
+                } else if RUST_KEYWORDS.contains(&&*key.value()) {
+                    // Rust keywords can't name a `custom_keyword!`, but have a `Token!`
+                    quote! { syn::Token![#ident] }
+                } else {
+                    // Modules don't see the `syn` imported by `expand`
+                    definitions.push(quote! {
+                        ::better_bae::__private::syn::custom_keyword!(#ident);
+                    });
+                    quote! { kw::#ident }
+                }
+            })
+            .collect::<Vec<_>>();
+
+        Some(quote! {
+            {
+                mod kw {
+                    #(#definitions)*
+                }
+
+                fn keywords(input: syn::parse::ParseStream) -> syn::Result<()> {
+                    // Keyword tokens don't match raw identifiers, such as `r#type` for `type`
+                    if let std::option::Option::Some((ident, _)) = input.cursor().ident() {
+                        let ident = ident.to_string();
+                        if let std::option::Option::Some(key) = ident.strip_prefix("r#") {
+                            if [#(#keys),*].contains(&key) {
+                                return syn::Result::Ok(());
+                            }
+                        }
+                    }
+
+                    let lookahead = input.lookahead1();
+                    if #(lookahead.peek(#tokens) ||)* false {
+                        syn::Result::Ok(())
+                    } else {
+                        syn::Result::Err(lookahead.error())
+                    }
+                }
+
+                keywords
+            }
+        })
+    }
+
+    /// Statements parsing the arguments from `content`, without the surrounding parentheses, until
+    /// it is empty or `stop` returns `true`.
+    ///
+    /// The arguments are walked by hand rather than with `Attribute::parse_nested_meta`, which is
+    /// only available in syn 2. Moving to it means moving the public API, whose types are syn 1's,
+    /// to syn 2 as well, so it is left for a major release.
+    fn parse_args(&self) -> TokenStream {
+        if self.options.list {
+            return self.parse_list_args();
+        }
+
         let attr_name = self.attr_name();
 
-        let variable_declarations = self.item.fields.iter().map(|field| {
-            let name = &field.ident;
-            quote! { let mut #name = std::option::Option::None; }
+        self.check_keys();
+        self.check_defaults();
+        self.check_when_absent();
+        self.check_conflicts();
+
+        let variable_declarations = self.argument_fields().map(|field| {
+            let var = field_var(&field.field);
+            quote! { let mut #var = std::option::Option::None; }
+        });
+        let rest_declaration = self.rest_field().map(|field| {
+            let var = field_var(&field.field);
+            let ty = &field.field.ty;
+            quote! { let mut #var: #ty = std::default::Default::default(); }
+        });
+        let switches_declaration = self.switches_field().map(|field| {
+            let var = field_var(&field.field);
+            let ty = &field.field.ty;
+            quote! { let mut #var: #ty = std::default::Default::default(); }
+        });
+        let flatten_declaration = self.flatten_field().map(|field| {
+            let var = field_var(&field.field);
+            quote! { let mut #var = proc_macro2::TokenStream::new(); }
+        });
+        let repeated_span_declarations = self.repeated_count_fields().map(|field| {
+            let spans = repeated_spans_var(&field.field);
+            quote! {
+                let mut #spans: std::vec::Vec<proc_macro2::Span> = std::vec::Vec::new();
+            }
         });
 
-        let match_arms = self.item.fields.iter().map(|field| {
-            let field_name = get_field_name(field);
-            let pattern = LitStr::new(&field_name.to_string(), field.span());
+        let match_arms = self.argument_fields().map(|bae_field| {
+            let field = &bae_field.field;
+            let var = field_var(field);
+            let key = field_key(bae_field);
+            let pattern = self.key_pattern(bae_field);
+
+            let check_duplicate = if bae_field.options.multiple || bae_field.options.repeated {
+                quote! {}
+            } else {
+                quote! {
+                    ::better_bae::__private::check_duplicate(#var.is_some(), #key, bae_attr_span)?;
+                }
+            };
+
+            let check_conflicts = self.conflicting_fields(bae_field).map(|other| {
+                let other_var = field_var(&other.field);
+                let message = format!(
+                    "`{}` can't be used together with `{}`",
+                    key.value(),
+                    field_key(other).value()
+                );
+                quote! {
+                    if #other_var.is_some() {
+                        return syn::Result::Err(syn::Error::new(bae_attr_span, #message));
+                    }
+                }
+            });
+            let check_forbidden = bae_field.options.forbidden_with.iter().map(|feature| {
+                quote! {
+                    ::better_bae::__private::check_forbidden_with(#feature, #key, bae_attr_span)?;
+                }
+            });
+            let check_duplicate = quote! {
+                #(#check_forbidden)*
+                #check_duplicate
+                #(#check_conflicts)*
+            };
 
             if field_is_switch(field) {
+                let takes_no_value = format!("`{}` is a flag and does not take a value", key.value());
                 quote! {
                     #pattern => {
-                        #field_name = std::option::Option::Some(());
+                        #check_duplicate
+                        if content.peek(syn::Token![=]) {
+                            return syn::Result::Err(content.error(#takes_no_value));
+                        }
+                        #var = std::option::Option::Some(());
                     }
                 }
             } else {
+                let value = string_transforms(bae_field, value_parser(bae_field, self.value_end()));
+                let value = if bae_field.options.transparent_errors
+                    || self.options.transparent_errors
+                {
+                    value
+                } else {
+                    quote! {
+                        ::better_bae::__private::with_key_context(
+                            &content,
+                            #key,
+                            |content: syn::parse::ParseStream| syn::Result::Ok(#value),
+                        )?
+                    }
+                };
+                let check_bounds = value_bounds_check(bae_field);
+                let check_one_of = value_one_of_check(bae_field);
+                let check_count = value_count_check(bae_field);
+                let store = if bae_field.options.repeated {
+                    // Each `key(...)` is one more element, whose span is kept to blame the first
+                    // one over the `count_range`
+                    let record_span = bae_field.options.count_bounds().map(|_| {
+                        let spans = repeated_spans_var(field);
+                        quote! { #spans.push(bae_attr_span); }
+                    });
+                    quote! {
+                        #var.get_or_insert_with(std::vec::Vec::new).push(value);
+                        #record_span
+                    }
+                } else {
+                    quote! { #var = std::option::Option::Some(value); }
+                };
+                let eq = if bae_field.options.nested_tokens
+                    || bae_field.options.meta_list
+                    || bae_field.options.repeated
+                {
+                    // `key(...)` has no `=`
+                    quote! {}
+                } else {
+                    quote! { ::better_bae::__private::parse_eq(content, #key, bae_attr_span)?; }
+                };
                 quote! {
                     #pattern => {
-                        content.parse::<syn::Token![=]>()?;
-                        #field_name = std::option::Option::Some(content.parse()?);
+                        #check_duplicate
+                        #eq
+                        let value_start = content.fork();
+                        let value = #value;
+                        #check_bounds
+                        #check_one_of
+                        #check_count
+                        #store
                     }
                 }
             }
         });
 
+        // A missing `Vec` field is empty, which may not meet its `count_range`
+        let check_missing_counts = self.argument_fields().filter_map(|bae_field| {
+            let (min, max) = bae_field.options.count_bounds()?;
+            if min == 0
+                || bae_field.options.default_value().is_some()
+                || self.defaults_when_absent(bae_field)
+            {
+                return None;
+            }
+            let var = field_var(&bae_field.field);
+            let message = count_message(bae_field, min, max);
+            Some(quote! {
+                if #var.is_none() {
+                    return syn::Result::Err(content.error(format!(#message, 0)));
+                }
+            })
+        });
+
+        // A `repeated` field's count is only known once all arguments have been parsed
+        let check_repeated_counts = self.repeated_count_fields().map(|bae_field| {
+            let (min, max) = bae_field.options.count_bounds().unwrap();
+            let spans = repeated_spans_var(&bae_field.field);
+            let message = count_message(bae_field, min, max);
+            let in_range = match max {
+                Some(max) if min == 0 => quote! { #spans.len() <= #max },
+                Some(max) => quote! { #spans.len() >= #min && #spans.len() <= #max },
+                None => quote! { #spans.len() >= #min },
+            };
+            let blamed = match max {
+                Some(max) => quote! { #spans.get(#max).or_else(|| #spans.last()) },
+                None => quote! { #spans.last() },
+            };
+            quote! {
+                if !#spans.is_empty() && !(#in_range) {
+                    return syn::Result::Err(syn::Error::new(
+                        *#blamed.unwrap(),
+                        format!(#message, #spans.len()),
+                    ));
+                }
+            }
+        });
+
         let unwrap_mandatory_fields = self
-            .item
-            .fields
-            .iter()
-            .filter(|field| !field_is_optional(field))
-            .map(|field| {
-                let field_name = get_field_name(field);
-                let arg_name = LitStr::new(&field_name.to_string(), field.span());
+            .argument_fields()
+            .filter(|field| field.options.is_required() || !field_is_optional(&field.field))
+            .map(|bae_field| {
+                let field = &bae_field.field;
+                let var = field_var(field);
+                let arg_name = field_key(bae_field);
 
-                quote! {
-                    let #field_name = if let std::option::Option::Some(#field_name) = #field_name {
-                        #field_name
-                    } else {
-                        return syn::Result::Err(
-                            input.error(
-                                &format!("`#[{}]` is missing `{}` argument", #attr_name, #arg_name),
-                            )
-                        );
-                    };
+                if !bae_field.options.is_required() {
+                    if let Some(default) = bae_field.options.default_value() {
+                        return quote! {
+                            let #var = #var.unwrap_or_else(|| #default);
+                        };
+                    } else if field_is_vec(field) || self.defaults_when_absent(bae_field) {
+                        return quote! {
+                            let #var = #var.unwrap_or_default();
+                        };
+                    }
+                }
+
+                let missing = quote! {
+                    return syn::Result::Err(
+                        content.error(
+                            &format!("`#[{}]` is missing `{}` argument", #attr_name, #arg_name),
+                        )
+                    );
+                };
+
+                if field_is_optional(field) {
+                    // Required but still stored as an `Option`
+                    quote! {
+                        if #var.is_none() {
+                            #missing
+                        }
+                    }
+                } else {
+                    quote! {
+                        let #var = if let std::option::Option::Some(value) = #var {
+                            value
+                        } else {
+                            #missing
+                        };
+                    }
                 }
             });
 
-        let set_fields = self.item.fields.iter().map(|field| {
-            let field_name = get_field_name(field);
-            quote! { #field_name, }
+        let set_fields = self.fields.iter().map(|field| {
+            let field_name = get_field_name(&field.field);
+            if field.options.is_argument()
+                || field.options.rest
+                || field.options.flatten
+                || field.options.catch_all_switches
+            {
+                let var = field_var(&field.field);
+                quote! { #field_name: #var, }
+            } else {
+                quote! { #field_name: std::default::Default::default(), }
+            }
         });
 
-        let code = quote! {
-            impl syn::parse::Parse for #struct_name {
-                #[allow(unreachable_code, unused_imports, unused_variables)]
-                fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-                    #(#variable_declarations)*
+        let on_unknown = if let Some(rest) = self.rest_field() {
+            let var = field_var(&rest.field);
+            let (key_ty, value_ty) = rest_types(&rest.field);
+            let value = type_parser(rest, value_ty, self.value_end());
+            let key = if type_is(key_ty, "String") {
+                quote! { bae_attr_key.to_string() }
+            } else {
+                quote! { syn::Ident::new(bae_attr_key, bae_attr_span) }
+            };
+            let (contains, insert) = if type_is(&rest.field.ty, "Vec") {
+                (
+                    quote! { #var.iter().any(|(existing, _)| *existing == key) },
+                    quote! { #var.push((key, value)); },
+                )
+            } else {
+                (
+                    quote! { #var.contains_key(&key) },
+                    quote! { #var.insert(key, value); },
+                )
+            };
+            quote! {
+                let key = #key;
+                ::better_bae::__private::check_duplicate(#contains, bae_attr_key, bae_attr_span)?;
+                if !content.peek(syn::Token![=]) {
+                    return syn::Result::Err(syn::Error::new(
+                        bae_attr_span,
+                        &format!("unknown flag `{}`, other arguments need a value", bae_attr_key),
+                    ));
+                }
+                content.parse::<syn::Token![=]>()?;
+                let value = ::better_bae::__private::with_key_context(
+                    &content,
+                    bae_attr_key,
+                    |content: syn::parse::ParseStream| syn::Result::Ok(#value),
+                )?;
+                #insert
+            }
+        } else if let Some(flatten) = self.flatten_field() {
+            let var = field_var(&flatten.field);
+            let ty = &flatten.field.ty;
+            let ty = if field_is_optional(&flatten.field) {
+                inner_type(ty).unwrap_or(ty)
+            } else {
+                ty
+            };
+            let string_keys = self.options.string_keys;
+            // Keys the flattened struct doesn't have are unknown to this one
+            quote! {
+                if !<#ty as ::better_bae::TryFromAttributes>::accepts_key(bae_attr_key) {
+                    return syn::Result::Ok(false);
+                }
+                ::better_bae::__private::capture_argument(content, bae_attr, #string_keys, &mut #var)?;
+            }
+        } else {
+            // Handled by `parse_arguments`
+            quote! { return syn::Result::Ok(false); }
+        };
 
-                    let content;
-                    syn::parenthesized!(content in input);
+        // Unknown flags go to the `catch_all_switches` field, before `rest` or `unknown` see them
+        let on_unknown_switch = self.switches_field().map(|field| {
+            let var = field_var(&field.field);
+            quote! {
+                if content.is_empty() || content.peek(syn::Token![,]) {
+                    ::better_bae::__private::check_duplicate(
+                        #var.iter().any(|existing| existing == bae_attr_key),
+                        bae_attr_key,
+                        bae_attr_span,
+                    )?;
+                    #var.push(syn::Ident::new(bae_attr_key, bae_attr_span));
+                    return syn::Result::Ok(true);
+                }
+            }
+        });
+        let on_unknown = quote! {
+            #on_unknown_switch
+            #on_unknown
+        };
 
-                    while !content.is_empty() {
-                        let bae_attr_ident = content.parse::<syn::Ident>()?;
+        // The flattened struct is parsed from the arguments with its keys. An optional one is
+        // `None` if there are none, as opposed to parsed without arguments
+        let parse_flattened = self.flatten_field().map(|flatten| {
+            let var = field_var(&flatten.field);
+            let ty = &flatten.field.ty;
+            let optional = field_is_optional(&flatten.field);
+            let ty = if optional {
+                inner_type(ty).unwrap_or(ty)
+            } else {
+                ty
+            };
+            let parse = quote! {
+                <#ty as ::better_bae::TryFromAttributes>::from_tokens(#var).map_err(|err| {
+                    ::better_bae::__private::flattened_error(
+                        err,
+                        <#ty as ::better_bae::TryFromAttributes>::attr_name(),
+                        #attr_name,
+                    )
+                })?
+            };
+            if optional {
+                quote! {
+                    let #var = if #var.is_empty() {
+                        std::option::Option::None
+                    } else {
+                        std::option::Option::Some(#parse)
+                    };
+                }
+            } else {
+                quote! {
+                    let #var = #parse;
+                }
+            }
+        });
 
-                        match &*bae_attr_ident.to_string() {
-                            #(#match_arms)*
-                            _ => {
-                                content.parse::<proc_macro2::TokenStream>()?;
-                            }
+        // With `env_prefix`, a missing argument is parsed from `PREFIX_KEY` if it is set
+        let env_defaults = self.options.env_prefix.iter().flat_map(|prefix| {
+            self.argument_fields()
+                .filter(|field| !field_is_switch(&field.field))
+                .map(move |bae_field| {
+                    let var = field_var(&bae_field.field);
+                    let name = format!(
+                        "{}{}",
+                        prefix.value(),
+                        field_key(bae_field).value().to_shouty_snake_case()
+                    );
+                    // The variable holds only the value, commas included
+                    let value = string_transforms(
+                        bae_field,
+                        value_parser(bae_field, ValueEnd::Separator(',')),
+                    );
+                    // Validated like a value given as an argument
+                    let check_bounds = value_bounds_check(bae_field);
+                    let check_one_of = value_one_of_check(bae_field);
+                    let check_count = value_count_check(bae_field);
+                    quote! {
+                        if #var.is_none() {
+                            #var = ::better_bae::__private::parse_env(
+                                #name,
+                                |content: syn::parse::ParseStream| {
+                                    let value_start = content.fork();
+                                    let value = #value;
+                                    #check_bounds
+                                    #check_one_of
+                                    #check_count
+                                    syn::Result::Ok(value)
+                                },
+                            )?;
                         }
-
-                        content.parse::<syn::Token![,]>().ok();
                     }
+                })
+        });
 
-                    #(#unwrap_mandatory_fields)*
-
-                    syn::Result::Ok(Self { #(#set_fields)* })
+        let check_any_field = if self.options.at_least_one_field {
+            if self.argument_fields().next().is_none() {
+                abort!(
+                    self.item.ident.span(),
+                    "`at_least_one_field` requires fields parsed from the arguments"
+                );
+            }
+            let vars = self.argument_fields().map(|field| field_var(&field.field));
+            let keys = self
+                .argument_fields()
+                .map(|field| format!("`{}`", field_key(field).value()))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let message = format!("`#[{}]` requires at least one of {}", attr_name.value(), keys);
+            quote! {
+                if #(#vars.is_none())&&* {
+                    return syn::Result::Err(content.error(#message));
                 }
             }
+        } else {
+            quote! {}
         };
-        self.tokens.extend(code);
-    }
-}
+
+        let after_parse = self.options.after_parse.as_ref().map(|hook| {
+            quote! { parsed.#hook(content)?; }
+        });
+        let arguments = self.arguments();
+
+        let check_marker = if self.is_marker() && self.options.after_parse.is_none() {
+            quote! {
+                if !content.is_empty() && !stop(content) {
+                    return syn::Result::Err(content.error("this attribute takes no arguments"));
+                }
+            }
+        } else {
+            quote! {}
+        };
+
+        quote! {
+            {
+                #check_marker
+                #(#variable_declarations)*
+                #rest_declaration
+                #flatten_declaration
+                #switches_declaration
+                #(#repeated_span_declarations)*
+
+                ::better_bae::__private::parse_arguments(
+                    content,
+                    stop,
+                    &#arguments,
+                    &mut |content: syn::parse::ParseStream,
+                          bae_attr: &::better_bae::__private::Key|
+                          -> syn::Result<bool> {
+                        let bae_attr_key = &*bae_attr.name;
+                        let bae_attr_span = bae_attr.span;
+                        match &*bae_attr.matched {
+                            #(#match_arms)*
+                            _ => {
+                                #on_unknown
+                            }
+                        }
+                        syn::Result::Ok(true)
+                    },
+                )?;
+
+                #(#env_defaults)*
+                #check_any_field
+                #(#check_missing_counts)*
+                #(#check_repeated_counts)*
+                #(#unwrap_mandatory_fields)*
+                #parse_flattened
+
+                #[allow(unused_mut)]
+                let mut parsed = Self { #(#set_fields)* };
+                #after_parse
+                syn::Result::Ok(parsed)
+            }
+        }
+    }
+
+    /// The field of a `#[bae(list)]` struct, which holds all the values.
+    fn list_field(&self) -> &BaeField {
+        let mut fields = self.argument_fields();
+        let has_other_fields = self.rest_field().is_some()
+            || self.flatten_field().is_some()
+            || self.switches_field().is_some();
+        match (fields.next(), fields.next()) {
+            (Some(field), None) if !has_other_fields => field,
+            _ => abort!(
+                self.item.ident.span(),
+                "`#[bae(list)]` requires exactly one field holding the values"
+            ),
+        }
+    }
+
+    /// Statements parsing the arguments of a `#[bae(list)]` struct, as values without keys.
+    fn parse_list_args(&self) -> TokenStream {
+        if let Some(hook) = &self.options.after_parse {
+            abort!(hook.span(), "`after_parse` can't be used with `#[bae(list)]`");
+        }
+
+        let list_field = self.list_field();
+        let value = type_parser(
+            list_field,
+            list_element_type(&list_field.field),
+            ValueEnd::Argument,
+        );
+
+        let set_fields = self.fields.iter().map(|field| {
+            let field_name = get_field_name(&field.field);
+            if field.options.is_argument() {
+                quote! { #field_name: values.into_iter().collect(), }
+            } else {
+                quote! { #field_name: std::default::Default::default(), }
+            }
+        });
+
+        quote! {
+            {
+                let mut values = std::vec::Vec::new();
+                while !content.is_empty() && !stop(content) {
+                    ::better_bae::__private::check_empty_argument(content)?;
+                    values.push(#value);
+                    if content.is_empty() || stop(content) {
+                        break;
+                    }
+                    content.parse::<syn::Token![,]>()?;
+                }
+
+                syn::Result::Ok(Self { #(#set_fields)* })
+            }
+        }
+    }
+
+    fn expand_to_tokens_impl(&mut self) {
+        if !self.options.to_tokens {
+            for field in &self.fields {
+                if field.options.skip_if_default {
+                    abort!(
+                        field.field.span(),
+                        "`skip_if_default` requires `#[bae(to_tokens)]` on the struct"
+                    );
+                }
+            }
+            return;
+        }
+
+        if self.options.list {
+            return self.expand_list_to_tokens_impl();
+        }
+
+        let struct_name = self.struct_name();
+        let (impl_generics, ty_generics, where_clause) = self.item.generics.split_for_impl();
+
+        let push_args = self.argument_fields().map(|bae_field| {
+            let field = &bae_field.field;
+            let field_name = get_field_name(field);
+            if bae_field.options.json {
+                abort!(field.span(), "`json` fields can't be used with `#[bae(to_tokens)]`");
+            }
+            if bae_field.options.include_str {
+                // Only the file's contents are kept, not its path
+                abort!(
+                    field.span(),
+                    "`include_str` fields can't be used with `#[bae(to_tokens)]`"
+                );
+            }
+            let key = field_key(bae_field);
+            let key = if self.options.string_keys {
+                quote! { &proc_macro2::Literal::string(#key) }
+            } else {
+                quote! { &proc_macro2::Ident::new(#key, proc_macro2::Span::call_site()) }
+            };
+
+            let push_fn = if bae_field.options.nested_tokens {
+                quote! { ::better_bae::__private::push_nested }
+            } else if bae_field.options.meta_list {
+                // The nested struct's tokens include the parentheses
+                quote! { ::better_bae::__private::push_group }
+            } else {
+                quote! { ::better_bae::__private::push_arg }
+            };
+
+            let push = if field_is_switch(field) {
+                quote! {
+                    if self.#field_name.is_some() {
+                        ::better_bae::__private::push_switch(&mut args, #key);
+                    }
+                }
+            } else if field_is_nullable(field) {
+                let null = bae_field.options.null_keyword();
+                quote! {
+                    match &self.#field_name {
+                        std::option::Option::Some(std::option::Option::Some(value)) => {
+                            #push_fn(&mut args, #key, value);
+                        }
+                        std::option::Option::Some(std::option::Option::None) => {
+                            #push_fn(
+                                &mut args,
+                                #key,
+                                &proc_macro2::Ident::new(#null, proc_macro2::Span::call_site()),
+                            );
+                        }
+                        std::option::Option::None => {}
+                    }
+                }
+            } else if bae_field.options.repeated {
+                // The nested structs' tokens include the parentheses
+                quote! {
+                    for value in &self.#field_name {
+                        ::better_bae::__private::push_group(&mut args, #key, value);
+                    }
+                }
+            } else if field_is_vec(field) && !bae_field.options.byte_str && !bae_field.options.items
+            {
+                let push_list = if bae_field.options.meta_list {
+                    quote! { ::better_bae::__private::push_meta_list }
+                } else {
+                    quote! { ::better_bae::__private::push_list }
+                };
+                let separator = bae_field.options.list_separator();
+                quote! {
+                    #push_list(&mut args, #key, &self.#field_name, #separator);
+                }
+            } else if field_is_optional(field) {
+                let value = value_tokens(bae_field, quote!(value));
+                quote! {
+                    if let std::option::Option::Some(value) = &self.#field_name {
+                        #push_fn(&mut args, #key, #value);
+                    }
+                }
+            } else {
+                let value = value_tokens(bae_field, quote!(&self.#field_name));
+                quote! {
+                    #push_fn(&mut args, #key, #value);
+                }
+            };
+
+            if bae_field.options.skip_if_default {
+                quote! {
+                    if self.#field_name != std::default::Default::default() {
+                        #push
+                    }
+                }
+            } else {
+                push
+            }
+        });
+
+        let push_rest = self.rest_field().map(|field| {
+            let field_name = get_field_name(&field.field);
+            let key = if type_is(rest_types(&field.field).0, "String") {
+                quote! { &proc_macro2::Ident::new(key, proc_macro2::Span::call_site()) }
+            } else {
+                quote! { key }
+            };
+            quote! {
+                for (key, value) in &self.#field_name {
+                    ::better_bae::__private::push_arg(&mut args, #key, value);
+                }
+            }
+        });
+
+        let push_switches = self.switches_field().map(|field| {
+            let field_name = get_field_name(&field.field);
+            quote! {
+                for switch in &self.#field_name {
+                    ::better_bae::__private::push_switch(&mut args, switch);
+                }
+            }
+        });
+
+        let push_flattened = self.flatten_field().map(|field| {
+            let field_name = get_field_name(&field.field);
+            if field_is_optional(&field.field) {
+                quote! {
+                    if let std::option::Option::Some(value) = &self.#field_name {
+                        ::better_bae::__private::push_flattened(&mut args, value);
+                    }
+                }
+            } else {
+                quote! {
+                    ::better_bae::__private::push_flattened(&mut args, &self.#field_name);
+                }
+            }
+        });
+
+        let code = quote! {
+            impl #impl_generics ::better_bae::__private::ToTokens for #struct_name #ty_generics #where_clause {
+                fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+                    let mut args = proc_macro2::TokenStream::new();
+                    #(#push_args)*
+                    #push_rest
+                    #push_switches
+                    #push_flattened
+                    tokens.extend(std::iter::once(proc_macro2::TokenTree::Group(
+                        proc_macro2::Group::new(proc_macro2::Delimiter::Parenthesis, args),
+                    )));
+                }
+            }
+        };
+        self.tokens.extend(code);
+    }
+
+    /// `ToTokens` for `#[bae(list)]` structs, which emits the values as `(a, b,)`.
+    fn expand_list_to_tokens_impl(&mut self) {
+        let struct_name = self.struct_name();
+        let (impl_generics, ty_generics, where_clause) = self.item.generics.split_for_impl();
+        let field_name = get_field_name(&self.list_field().field);
+
+        let code = quote! {
+            impl #impl_generics ::better_bae::__private::ToTokens for #struct_name #ty_generics #where_clause {
+                fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+                    let mut args = proc_macro2::TokenStream::new();
+                    for value in &self.#field_name {
+                        ::better_bae::__private::ToTokens::to_tokens(value, &mut args);
+                        ::better_bae::__private::ToTokens::to_tokens(
+                            &<syn::Token![,]>::default(),
+                            &mut args,
+                        );
+                    }
+                    tokens.extend(std::iter::once(proc_macro2::TokenTree::Group(
+                        proc_macro2::Group::new(proc_macro2::Delimiter::Parenthesis, args),
+                    )));
+                }
+            }
+        };
+        self.tokens.extend(code);
+    }
+
+    /// The lines of the struct's `#[doc]` attributes joined by newlines, without the space that
+    /// follows `///`.
+    fn doc(&self) -> String {
+        doc_comment(&self.item.attrs)
+    }
+
+    /// Inherent versions of the trait's constructors, so callers don't need `TryFromAttributes`
+    /// in scope.
+    fn expand_inherent_methods(&mut self) {
+        let struct_name = self.struct_name();
+        let (impl_generics, ty_generics, where_clause) = self.item.generics.split_for_impl();
+
+        let doc = self.doc();
+        let fields = self.argument_fields().map(|bae_field| {
+            let name = field_key(bae_field);
+            let doc = doc_comment(&bae_field.field.attrs);
+            let since = match &bae_field.options.since {
+                Some(since) => quote! { std::option::Option::Some(#since) },
+                None => quote! { std::option::Option::None },
+            };
+            let deprecated = bae_field.options.deprecated;
+            quote! {
+                ::better_bae::FieldInfo {
+                    name: #name,
+                    doc: #doc,
+                    since: #since,
+                    deprecated: #deprecated,
+                },
+            }
+        });
+        let matched_suffix = self.matched_suffix_field().map(|field| {
+            let field_name = get_field_name(&field.field);
+            quote! {
+                /// What followed the `name_prefix` in the name of the parsed attribute.
+                pub fn matched_suffix(&self) -> &str {
+                    &self.#field_name
+                }
+            }
+        });
+
+        let code = quote! {
+            #[allow(dead_code)]
+            impl #impl_generics #struct_name #ty_generics #where_clause {
+                /// The struct's doc comment, for help messages in diagnostics.
+                pub const DOC: &'static str = #doc;
+
+                /// A description of each argument, in field order.
+                pub const FIELDS: &'static [::better_bae::FieldInfo] = &[#(#fields)*];
+
+                /// Same as `TryFromAttributes::from_attributes`.
+                pub fn from_attributes(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+                    <Self as ::better_bae::TryFromAttributes>::from_attributes(attrs)
+                }
+
+                /// Same as `TryFromAttributes::try_from_attributes`.
+                pub fn try_from_attributes(
+                    attrs: &[syn::Attribute],
+                ) -> syn::Result<std::option::Option<Self>> {
+                    <Self as ::better_bae::TryFromAttributes>::try_from_attributes(attrs)
+                }
+
+                #matched_suffix
+            }
+        };
+        self.tokens.extend(code);
+    }
+
+    /// `merge`, with `#[bae(merge)]`, which layers another parsed attribute over this one.
+    fn expand_merge_method(&mut self) {
+        if !self.options.merge {
+            return;
+        }
+
+        let struct_name = self.struct_name();
+        let (impl_generics, ty_generics, where_clause) = self.item.generics.split_for_impl();
+
+        let merge_fields = self.fields.iter().map(|bae_field| {
+            let field = &bae_field.field;
+            let field_name = get_field_name(field);
+            let is_collection = bae_field.options.rest
+                || (self.options.list && bae_field.options.is_argument())
+                || field_is_vec(field);
+
+            if is_collection {
+                quote! { self.#field_name.extend(other.#field_name); }
+            } else if field_is_optional(field) {
+                quote! {
+                    if other.#field_name.is_some() {
+                        self.#field_name = other.#field_name;
+                    }
+                }
+            } else {
+                quote! { self.#field_name = other.#field_name; }
+            }
+        });
+
+        let code = quote! {
+            #[allow(dead_code)]
+            impl #impl_generics #struct_name #ty_generics #where_clause {
+                /// Merges `other` into `self`. `other`'s values override `self`'s, except for
+                /// optional fields which `other` doesn't have, and lists which are appended to.
+                pub fn merge(&mut self, other: Self) {
+                    #(#merge_fields)*
+                }
+            }
+        };
+        self.tokens.extend(code);
+    }
+
+    /// `present_args`, with `#[bae(to_tokens)]`, which lists the arguments that were given. The
+    /// values are displayed through their `ToTokens` impl, which `to_tokens` already requires.
+    fn expand_present_args_method(&mut self) {
+        if !self.options.to_tokens || self.options.list {
+            return;
+        }
+
+        let struct_name = self.struct_name();
+        let (impl_generics, ty_generics, where_clause) = self.item.generics.split_for_impl();
+
+        let push_args = self.argument_fields().map(|bae_field| {
+            let field = &bae_field.field;
+            let field_name = get_field_name(field);
+            let key = field_key(bae_field);
+            let display = |value: TokenStream| {
+                quote! {
+                    std::option::Option::Some(
+                        ::better_bae::__private::ToTokens::to_token_stream(#value).to_string(),
+                    )
+                }
+            };
+
+            if field_is_switch(field) {
+                quote! {
+                    if self.#field_name.is_some() {
+                        present.push((#key, std::option::Option::None));
+                    }
+                }
+            } else if field_is_nullable(field) {
+                let value = display(quote!(value));
+                let null = bae_field.options.null_keyword();
+                quote! {
+                    match &self.#field_name {
+                        std::option::Option::Some(std::option::Option::Some(value)) => {
+                            present.push((#key, #value));
+                        }
+                        std::option::Option::Some(std::option::Option::None) => {
+                            present.push((#key, std::option::Option::Some(#null.to_string())));
+                        }
+                        std::option::Option::None => {}
+                    }
+                }
+            } else if bae_field.options.repeated {
+                let value = display(quote!(value));
+                quote! {
+                    for value in &self.#field_name {
+                        present.push((#key, #value));
+                    }
+                }
+            } else if field_is_vec(field) {
+                let value = if bae_field.options.byte_str || bae_field.options.items {
+                    display(value_tokens(bae_field, quote!(&self.#field_name)))
+                } else {
+                    quote! {
+                        std::option::Option::Some(
+                            ::better_bae::__private::display_list(&self.#field_name),
+                        )
+                    }
+                };
+                quote! {
+                    if !self.#field_name.is_empty() {
+                        present.push((#key, #value));
+                    }
+                }
+            } else if field_is_optional(field) {
+                let value = display(value_tokens(bae_field, quote!(value)));
+                quote! {
+                    if let std::option::Option::Some(value) = &self.#field_name {
+                        present.push((#key, #value));
+                    }
+                }
+            } else {
+                let value = display(value_tokens(bae_field, quote!(&self.#field_name)));
+                quote! {
+                    present.push((#key, #value));
+                }
+            }
+        });
+
+        let code = quote! {
+            #[allow(dead_code)]
+            impl #impl_generics #struct_name #ty_generics #where_clause {
+                /// The key of each argument that was given, in field order, with its value as
+                /// tokens. Flags have no value. Empty lists are left out, while fields with a
+                /// default are listed whether or not the argument was given.
+                pub fn present_args(
+                    &self,
+                ) -> impl std::iter::Iterator<
+                    Item = (&'static str, std::option::Option<std::string::String>),
+                > {
+                    let mut present = std::vec::Vec::new();
+                    #(#push_args)*
+                    present.into_iter()
+                }
+            }
+        };
+        self.tokens.extend(code);
+    }
+
+    fn expand_try_from_impl(&mut self) {
+        if !self.options.try_from {
+            return;
+        }
+
+        let struct_name = self.struct_name();
+        let (impl_generics, ty_generics, where_clause) = self.item.generics.split_for_impl();
+
+        let code = quote! {
+            impl #impl_generics std::convert::TryFrom<&syn::Attribute> for #struct_name #ty_generics #where_clause {
+                type Error = syn::Error;
+
+                fn try_from(attr: &syn::Attribute) -> syn::Result<Self> {
+                    match <Self as ::better_bae::TryFromAttributes>::try_from_attributes(
+                        std::slice::from_ref(attr),
+                    )? {
+                        std::option::Option::Some(parsed) => syn::Result::Ok(parsed),
+                        std::option::Option::None => syn::Result::Err(syn::Error::new_spanned(
+                            &attr.path,
+                            format!(
+                                "expected a `#[{}]` attribute",
+                                <Self as ::better_bae::TryFromAttributes>::attr_name(),
+                            ),
+                        )),
+                    }
+                }
+            }
+        };
+        self.tokens.extend(code);
+    }
+
+    fn expand_into_inner_method(&mut self) {
+        if !self.options.transparent {
+            return;
+        }
+
+        let mut fields = self.item.fields.iter();
+        let field = match (fields.next(), fields.next()) {
+            (Some(field), None) => field,
+            _ => abort!(
+                self.item.ident.span(),
+                "`#[bae(transparent)]` requires exactly one field"
+            ),
+        };
+
+        let struct_name = self.struct_name();
+        let (impl_generics, ty_generics, where_clause) = self.item.generics.split_for_impl();
+        let field_name = get_field_name(field);
+        let field_ty = &field.ty;
+
+        let code = quote! {
+            impl #impl_generics #struct_name #ty_generics #where_clause {
+                /// Consumes the attribute, returning its only field.
+                pub fn into_inner(self) -> #field_ty {
+                    self.#field_name
+                }
+            }
+        };
+        self.tokens.extend(code);
+    }
+}
+
+/// The text of the `///` comments among `attrs`, one line per comment.
+fn doc_comment(attrs: &[Attribute]) -> String {
+    let lines = attrs.iter().filter_map(|attr| {
+        if !attr.path.is_ident("doc") {
+            return None;
+        }
+        match attr.parse_meta() {
+            Ok(Meta::NameValue(MetaNameValue {
+                lit: Lit::Str(lit), ..
+            })) => Some(lit.value()),
+            _ => None,
+        }
+    });
+
+    lines
+        .map(|line| line.strip_prefix(' ').map(str::to_string).unwrap_or(line))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The expression that parses a field's value from `content`, after the `=`.
+fn value_parser(field: &BaeField, end: ValueEnd) -> TokenStream {
+    let ty = value_type(&field.field);
+
+    let separator = field.options.list_separator();
+    let element_end = ValueEnd::Separator(separator.value());
+
+    if field.options.json {
+        // Spanned so a missing `serde-json` feature is reported on the field
+        return quote_spanned! {get_field_name(&field.field).span()=>
+            ::better_bae::__private::parse_json!(&content)?
+        };
+    }
+
+    if field.options.byte_str {
+        let is_bytes = type_is(ty, "Vec") && inner_type(ty).is_some_and(|ty| type_is(ty, "u8"));
+        if !is_bytes {
+            abort!(field.field.ty.span(), "`byte_str` requires a `Vec<u8>` field");
+        }
+        return quote! { content.parse::<syn::LitByteStr>()?.value() };
+    }
+
+    if field.options.items {
+        if !type_is(ty, "Vec") {
+            abort!(field.field.ty.span(), "`items` requires a `Vec<T>` field");
+        }
+        return quote! { ::better_bae::__private::parse_items(&content)? };
+    }
+
+    if field.options.include_str {
+        if !type_is(ty, "String") {
+            abort!(
+                field.field.ty.span(),
+                "`include_str` requires a `String` field"
+            );
+        }
+        return quote! { ::better_bae::__private::parse_include_str(&content)? };
+    }
+
+    if field.options.ident_or_str {
+        if !type_is(ty, "String") {
+            abort!(
+                field.field.ty.span(),
+                "`ident_or_str` requires a `String` field"
+            );
+        }
+        return quote! { ::better_bae::__private::parse_ident_or_str(&content)? };
+    }
+
+    if field.options.repeated {
+        let inner = match inner_type(ty) {
+            Some(inner) if type_is(ty, "Vec") => inner,
+            _ => abort!(field.field.ty.span(), "`repeated` requires a `Vec<T>` field"),
+        };
+        if field.options.meta_list || field.options.nested_tokens {
+            abort!(
+                field.field.span(),
+                "`repeated` can't be combined with `meta_list` or `nested_tokens`"
+            );
+        }
+        return quote! {
+            ::better_bae::__private::parse_parenthesized_with(
+                &content,
+                <#inner as ::better_bae::TryFromAttributes>::parse_args,
+            )?
+        };
+    }
+
+    if field.options.meta_list {
+        if field_is_vec(&field.field) {
+            let inner = type_parser(field, inner_type(ty).unwrap_or(ty), element_end);
+            return quote! {
+                ::better_bae::__private::parse_meta_list(
+                    &content,
+                    #separator,
+                    |content: syn::parse::ParseStream| syn::Result::Ok(#inner),
+                )?
+            };
+        }
+
+        return quote! {
+            ::better_bae::__private::parse_parenthesized_with(
+                &content,
+                <#ty as ::better_bae::TryFromAttributes>::parse_args,
+            )?
+        };
+    }
+
+    if field_is_vec(&field.field) {
+        let inner = type_parser(field, inner_type(ty).unwrap_or(ty), element_end);
+        return quote! {
+            ::better_bae::__private::parse_list(
+                &content,
+                #separator,
+                |content: syn::parse::ParseStream| syn::Result::Ok(#inner),
+            )?
+        };
+    }
+
+    if field_is_nullable(&field.field) {
+        let inner = type_parser(field, inner_type(ty).unwrap_or(ty), end);
+        let null = field.options.null_keyword();
+        return quote! {
+            ::better_bae::__private::parse_nullable(
+                &content,
+                #null,
+                |content: syn::parse::ParseStream| syn::Result::Ok(#inner),
+            )?
+        };
+    }
+
+    type_parser(field, ty, end)
+}
+
+/// Where a greedily parsed value, such as a `syn::Expr`, ends.
+#[derive(Clone, Copy)]
+enum ValueEnd {
+    /// At a top level `,`, or where the `stop` of `parse_until` returns true.
+    Argument,
+    /// At the arguments of an `after_parse` struct, which may be followed by anything. `Expr`
+    /// and `Visibility` values are then parsed by their own parser, only `greedy` fields read up
+    /// to a `,`.
+    AfterParse,
+    /// At a top level list separator, with no `stop`.
+    Separator(char),
+}
+
+impl ValueEnd {
+    /// The expression parsing a `T` greedily from `content`.
+    fn parse_greedy(self) -> TokenStream {
+        match self {
+            ValueEnd::Argument | ValueEnd::AfterParse => {
+                quote! { ::better_bae::__private::parse_greedy(&content, ',', stop)? }
+            }
+            ValueEnd::Separator(separator) => quote! {
+                ::better_bae::__private::parse_greedy(&content, #separator, |_| false)?
+            },
+        }
+    }
+}
+
+/// The expression that parses a value of type `ty` from `content`.
+///
+/// With `parse_as` the value is parsed as that type and then converted to `ty`.
+fn type_parser(field: &BaeField, ty: &Type, end: ValueEnd) -> TokenStream {
+    let parse_as = if let Some(parse_as) = &field.options.parse_as {
+        parse_as
+    } else {
+        return direct_type_parser(field, ty, end);
+    };
+
+    let parsed = direct_type_parser(field, parse_as, end);
+    if type_is(parse_as, "LitInt") || type_is(parse_as, "LitFloat") {
+        quote! {
+            {
+                let value: #parse_as = #parsed;
+                value.base10_parse::<#ty>()?
+            }
+        }
+    } else {
+        quote! {
+            {
+                let value: #parse_as = #parsed;
+                <#ty as std::convert::From<#parse_as>>::from(value)
+            }
+        }
+    }
+}
+
+fn direct_type_parser(field: &BaeField, ty: &Type, end: ValueEnd) -> TokenStream {
+    if field.options.nested_tokens {
+        quote! { ::better_bae::__private::parse_parenthesized(&content)? }
+    } else if let Some(elems) = tuple_elems(ty) {
+        // A parenthesized value per element, as in `range = (1, 10)`
+        let len = elems.len();
+        let values = elems.iter().enumerate().map(|(index, elem)| {
+            let value = direct_type_parser(field, elem, ValueEnd::Separator(','));
+            quote! {
+                {
+                    ::better_bae::__private::tuple_value(content, #index, #len)?;
+                    #value
+                },
+            }
+        });
+        quote! {
+            ::better_bae::__private::parse_parenthesized_with(
+                &content,
+                |content: syn::parse::ParseStream| {
+                    let value = (#(#values)*);
+                    ::better_bae::__private::tuple_value(content, #len, #len)?;
+                    syn::Result::Ok(value)
+                },
+            )?
+        }
+    } else if field.options.greedy {
+        end.parse_greedy()
+    } else if (type_is(ty, "Expr") || type_is(ty, "Visibility"))
+        && !matches!(end, ValueEnd::AfterParse)
+    {
+        // Expressions are greedy so a value like `a < b` can't end early. So are visibilities,
+        // which would otherwise parse an empty value as inherited
+        end.parse_greedy()
+    } else if type_is(ty, "String") {
+        quote! { content.parse::<syn::LitStr>()?.value() }
+    } else if type_is(ty, "Pat") && !field.options.single_pat {
+        quote! { ::better_bae::__private::parse_multi_pat(&content)? }
+    } else if type_is(ty, "Attribute") {
+        quote! { ::better_bae::__private::parse_attribute(&content)? }
+    } else if type_is(ty, "LitInt") {
+        quote! { ::better_bae::__private::parse_lit_int(&content)? }
+    } else if type_is(ty, "LitFloat") {
+        quote! { ::better_bae::__private::parse_lit_float(&content)? }
+    } else if type_is_integer(ty) {
+        quote! { ::better_bae::__private::parse_int::<#ty>(&content)? }
+    } else if type_is_float(ty) {
+        quote! { ::better_bae::__private::parse_float::<#ty>(&content)? }
+    } else {
+        quote! { content.parse()? }
+    }
+}
+
+/// Applies `trim`, then `lowercase` or `uppercase`, to the parsed `value` of a `String` field.
+fn string_transforms(field: &BaeField, value: TokenStream) -> TokenStream {
+    let FieldOptions {
+        trim,
+        lowercase,
+        uppercase,
+        ..
+    } = field.options;
+    if !trim && !lowercase && !uppercase {
+        return value;
+    }
+    if !type_is(value_type(&field.field), "String") {
+        abort!(
+            field.field.ty.span(),
+            "`trim`, `lowercase` and `uppercase` require a `String` field"
+        );
+    }
+    if lowercase && uppercase {
+        abort!(
+            field.field.span(),
+            "`lowercase` can't be used together with `uppercase`"
+        );
+    }
+
+    let mut transformed = quote! { value };
+    if trim {
+        transformed = quote! { #transformed.trim() };
+    }
+    transformed = if lowercase {
+        quote! { #transformed.to_lowercase() }
+    } else if uppercase {
+        quote! { #transformed.to_uppercase() }
+    } else {
+        quote! { #transformed.to_string() }
+    };
+    quote! {
+        {
+            let value: std::string::String = #value;
+            #transformed
+        }
+    }
+}
+
+/// The value of a field with `#[bae(to_tokens)]`, from `value`, a reference to it.
+fn value_tokens(field: &BaeField, value: TokenStream) -> TokenStream {
+    if field.options.byte_str {
+        quote! { &syn::LitByteStr::new(#value, proc_macro2::Span::call_site()) }
+    } else if field.options.items {
+        quote! { &::better_bae::__private::Items(#value) }
+    } else if let Some(elems) = tuple_elems(value_type(&field.field)) {
+        let indices = (0..elems.len()).map(Index::from);
+        quote! { &::better_bae::__private::Tuple(&[#(&(#value).#indices),*]) }
+    } else {
+        value
+    }
+}
+
+/// Checks `value` against the field's `min` and `max`, if any.
+///
+/// Errors span all the tokens of the value, from `value_start` up to `content`.
+fn value_bounds_check(field: &BaeField) -> TokenStream {
+    let FieldOptions { min, max, .. } = &field.options;
+    if min.is_none() && max.is_none() {
+        return quote! {};
+    }
+
+    let ty = value_type(&field.field);
+    if !type_is_integer(ty) && !type_is_float(ty) {
+        abort!(
+            field.field.span(),
+            "`min` and `max` are only supported on integer and float fields"
+        );
+    }
+
+    let key = field_key(field).value();
+    let check = |lit: &Lit, op: TokenStream| {
+        let bound = bound_literal(lit, ty);
+        quote! { value #op #bound }
+    };
+    let (in_bounds, message) = match (min, max) {
+        (Some(min), Some(max)) => (
+            {
+                let (min_check, max_check) = (check(min, quote!(>=)), check(max, quote!(<=)));
+                quote! { #min_check && #max_check }
+            },
+            format!(
+                "`{}` must be between {} and {}",
+                key,
+                number_digits(min),
+                number_digits(max)
+            ),
+        ),
+        (Some(min), None) => (
+            check(min, quote!(>=)),
+            format!("`{}` must be at least {}", key, number_digits(min)),
+        ),
+        (None, Some(max)) => (
+            check(max, quote!(<=)),
+            format!("`{}` must be at most {}", key, number_digits(max)),
+        ),
+        (None, None) => unreachable!(),
+    };
+
+    quote! {
+        if !(#in_bounds) {
+            return syn::Result::Err(syn::Error::new_spanned(
+                ::better_bae::__private::tokens_between(&value_start, &content),
+                #message,
+            ));
+        }
+    }
+}
+
+/// Checks the number of values of a `Vec` field against its `count_range`.
+fn value_count_check(field: &BaeField) -> TokenStream {
+    let (min, max) = if let Some(bounds) = field.options.count_bounds() {
+        bounds
+    } else {
+        return quote! {};
+    };
+
+    if !field_is_vec(&field.field) {
+        abort!(field.field.span(), "`count_range` is only supported on `Vec` fields");
+    }
+    if field.options.repeated {
+        // Checked once all the `key(...)` have been parsed
+        return quote! {};
+    }
+
+    let message = count_message(field, min, max);
+    let in_range = match max {
+        Some(max) if min == 0 => quote! { value.len() <= #max },
+        Some(max) => quote! { value.len() >= #min && value.len() <= #max },
+        None => quote! { value.len() >= #min },
+    };
+    quote! {
+        if !(#in_range) {
+            return syn::Result::Err(syn::Error::new_spanned(
+                ::better_bae::__private::tokens_between(&value_start, &content),
+                format!(#message, value.len()),
+            ));
+        }
+    }
+}
+
+/// Checks a `one_of` field's value, after any `lowercase` or `uppercase`, is one of the allowed
+/// strings.
+fn value_one_of_check(field: &BaeField) -> TokenStream {
+    let one_of = if let Some(one_of) = &field.options.one_of {
+        one_of
+    } else {
+        return quote! {};
+    };
+
+    if !type_is(value_type(&field.field), "String") {
+        abort!(field.field.ty.span(), "`one_of` requires a `String` field");
+    }
+
+    let allowed = one_of.value();
+    let allowed: Vec<&str> = allowed.split('|').map(str::trim).collect();
+    for value in &allowed {
+        if value.is_empty() {
+            abort!(one_of.span(), "`one_of` has an empty value");
+        }
+        let normalized = if field.options.lowercase {
+            value.to_lowercase()
+        } else if field.options.uppercase {
+            value.to_uppercase()
+        } else {
+            continue;
+        };
+        if normalized != *value {
+            abort!(
+                one_of.span(),
+                "`{}` can never match, as the value is normalized to `{}`",
+                value,
+                normalized
+            );
+        }
+    }
+
+    let message = format!(
+        "`{}` must be one of: {}",
+        field_key(field).value(),
+        allowed
+            .iter()
+            .map(|value| format!("`{}`", value))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    quote! {
+        if ![#(#allowed),*].contains(&value.as_str()) {
+            return syn::Result::Err(syn::Error::new_spanned(
+                ::better_bae::__private::tokens_between(&value_start, &content),
+                #message,
+            ));
+        }
+    }
+}
+
+/// The error for a `count_range` which isn't met, with `{}` in place of the number of values.
+fn count_message(field: &BaeField, min: usize, max: Option<usize>) -> String {
+    let key = field_key(field).value();
+    match max {
+        Some(max) if max == min => format!("expected {} `{}` entries, found {{}}", min, key),
+        Some(max) if min == 0 => format!("expected at most {} `{}` entries, found {{}}", max, key),
+        Some(max) => format!("expected {} to {} `{}` entries, found {{}}", min, max, key),
+        None => format!("expected at least {} `{}` entries, found {{}}", min, key),
+    }
+}
+
+/// A `min` or `max` bound written as an unsuffixed literal of the field's type, so `min = 0` can
+/// be compared with an `f64`.
+fn bound_literal(lit: &Lit, ty: &Type) -> Lit {
+    let digits = number_digits(lit);
+    if type_is_float(ty) {
+        let digits = match lit {
+            Lit::Int(_) => format!("{}.0", digits),
+            _ => digits.to_string(),
+        };
+        Lit::Float(LitFloat::new(&digits, lit.span()))
+    } else if let Lit::Float(_) = lit {
+        abort!(lit.span(), "bounds of integer fields must be integers")
+    } else {
+        Lit::Int(LitInt::new(digits, lit.span()))
+    }
+}
+
+fn number_digits(lit: &Lit) -> &str {
+    match lit {
+        Lit::Int(lit) => lit.base10_digits(),
+        Lit::Float(lit) => lit.base10_digits(),
+        _ => unreachable!("bounds are validated to be numbers"),
+    }
+}
+
+/// The type of the values given for a field, that is without the `Option` of optional fields.
+fn value_type(field: &Field) -> &Type {
+    if field_is_optional(field) {
+        inner_type(&field.ty).unwrap_or(&field.ty)
+    } else {
+        &field.ty
+    }
+}
+
+/// The element types of a tuple type other than `()`.
+fn tuple_elems(ty: &Type) -> Option<Vec<&Type>> {
+    match ty {
+        Type::Tuple(tuple) if !tuple.elems.is_empty() => Some(tuple.elems.iter().collect()),
+        _ => None,
+    }
+}
+
+/// Whether the last segment of a type path is `name`, such as `syn::Attribute` and `Attribute`
+/// for `"Attribute"`.
+fn type_is(ty: &Type, name: &str) -> bool {
+    match ty {
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == name),
+        _ => false,
+    }
+}
+
+/// Checks that a `parse_quote_default` string parses as the field type, for the `syn` types
+/// known to this crate. Other types are only checked when the generated code is compiled.
+fn check_parse_quote_default(ty: &Type, default: &LitStr) {
+    macro_rules! check {
+        ($($name:ident),*) => {
+            $(
+                if type_is(ty, stringify!($name)) {
+                    if let Err(err) = default.parse::<$name>() {
+                        abort!(
+                            default.span(),
+                            "`{}` is not a valid `{}`: {}",
+                            default.value(),
+                            stringify!($name),
+                            err
+                        );
+                    }
+                    return;
+                }
+            )*
+        };
+    }
+    check!(
+        Type, Path, Ident, Expr, Lit, LitStr, LitInt, LitFloat, LitBool, Block, Visibility,
+        Signature, Generics, Pat
+    );
+}
 
 fn get_field_name(field: &Field) -> &Ident {
     field
@@ -190,6 +2253,49 @@ fn get_field_name(field: &Field) -> &Ident {
         .unwrap_or_else(|| abort!(field.span(), "Field without a name"))
 }
 
+/// The local variable holding the spans of a `repeated` field's keys, to check its
+/// `count_range`.
+fn repeated_spans_var(field: &Field) -> Ident {
+    format_ident!("__bae_{}_spans", get_field_name(field).unraw())
+}
+
+/// The local variable holding a field's value while parsing.
+///
+/// Prefixed so fields can't shadow the other variables in the generated code.
+fn field_var(field: &Field) -> Ident {
+    format_ident!("__bae_{}", get_field_name(field).unraw())
+}
+
+/// The key used for a field in the attribute's arguments.
+///
+/// Computed once in `FromAttributes::new`, from `rename` or the unrawed field name with its
+/// prefix and suffix, so a field named `r#type` is given as `type = ...`.
+fn field_key(field: &BaeField) -> LitStr {
+    field.key.clone()
+}
+
+/// Folds a key for `case_insensitive` matching, so `MandatoryIdent` matches `mandatory_ident`.
+///
+/// Must match `better_bae::__private::fold_key`.
+fn fold_key(key: &str) -> String {
+    key.chars()
+        .filter(|c| *c != '_')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn type_is_integer(ty: &Type) -> bool {
+    [
+        "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+    ]
+    .iter()
+    .any(|name| type_is(ty, name))
+}
+
+fn type_is_float(ty: &Type) -> bool {
+    type_is(ty, "f32") || type_is(ty, "f64")
+}
+
 fn field_is_optional(field: &Field) -> bool {
     let type_path = if let Type::Path(type_path) = &field.ty {
         type_path
@@ -207,6 +2313,79 @@ fn field_is_optional(field: &Field) -> bool {
     ident == "Option"
 }
 
+/// Whether a field is an `Option<Option<T>>`, where `Some(None)` is given as the null keyword.
+fn field_is_nullable(field: &Field) -> bool {
+    field_is_optional(field) && type_is(value_type(field), "Option")
+}
+
+/// The types of the keys and values collected by a `#[bae(rest)]` field, `K` and `T` in
+/// `Vec<(K, T)>`, `IndexMap<K, T>` or `HashMap<K, T>`.
+fn rest_types(field: &Field) -> (&Type, &Type) {
+    let types = if type_is(&field.ty, "Vec") {
+        match inner_type(&field.ty) {
+            Some(Type::Tuple(tuple)) if tuple.elems.len() == 2 => {
+                tuple.elems.first().zip(tuple.elems.last())
+            }
+            _ => None,
+        }
+    } else if type_is(&field.ty, "IndexMap") || type_is(&field.ty, "HashMap") {
+        first_type_argument(&field.ty).zip(inner_type(&field.ty))
+    } else {
+        None
+    };
+
+    match types {
+        Some((key_ty, value_ty)) if type_is(key_ty, "Ident") || type_is(key_ty, "String") => {
+            (key_ty, value_ty)
+        }
+        _ => abort!(
+            field.ty.span(),
+            "`#[bae(rest)]` fields must be `Vec<(K, T)>`, `IndexMap<K, T>` or `HashMap<K, T>`, \
+             where `K` is `syn::Ident` or `String`"
+        ),
+    }
+}
+
+/// The type of the values of a `#[bae(list)]` field, `T` in `Vec<T>` or `Punctuated<T, P>`.
+fn list_element_type(field: &Field) -> &Type {
+    let element_ty = if type_is(&field.ty, "Vec") {
+        inner_type(&field.ty)
+    } else if type_is(&field.ty, "Punctuated") {
+        first_type_argument(&field.ty)
+    } else {
+        None
+    };
+
+    element_ty.unwrap_or_else(|| {
+        abort!(
+            field.ty.span(),
+            "`#[bae(list)]` fields must be `Vec<T>` or `Punctuated<T, Token![,]>`"
+        )
+    })
+}
+
+/// The first generic type argument of a type path, `T` in `Punctuated<T, P>`.
+fn first_type_argument(ty: &Type) -> Option<&Type> {
+    let type_path = if let Type::Path(type_path) = ty {
+        type_path
+    } else {
+        return None;
+    };
+
+    match &type_path.path.segments.last()?.arguments {
+        PathArguments::AngleBracketed(args) => args.args.iter().find_map(|arg| match arg {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        }),
+        _ => None,
+    }
+}
+
+/// Whether a field is a `Vec<T>`, given as a list such as `key = [a, b]`.
+fn field_is_vec(field: &Field) -> bool {
+    type_is(&field.ty, "Vec") && inner_type(&field.ty).is_some()
+}
+
 fn field_is_switch(field: &Field) -> bool {
     let unit_type = syn::parse_str::<Type>("()").unwrap();
     inner_type(&field.ty) == Some(&unit_type)
@@ -0,0 +1,426 @@
+use proc_macro_error::*;
+use syn::{
+    ext::IdentExt,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    *,
+};
+
+/// A single `key` or `key = value` item inside `#[bae(...)]`.
+#[derive(Debug)]
+pub(crate) struct BaeOption {
+    pub(crate) key: Ident,
+    pub(crate) value: Option<Lit>,
+}
+
+impl Parse for BaeOption {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let key = input.call(Ident::parse_any)?;
+        let value = if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            Some(parse_value(input)?)
+        } else {
+            None
+        };
+        Ok(Self { key, value })
+    }
+}
+
+/// Parses a literal, allowing negative numbers such as `-5`.
+fn parse_value(input: ParseStream) -> Result<Lit> {
+    if !input.peek(Token![-]) {
+        return input.parse();
+    }
+
+    let span = input.parse::<Token![-]>()?.spans[0];
+    match input.parse()? {
+        Lit::Int(lit) => Ok(Lit::Int(LitInt::new(&format!("-{}", lit), span))),
+        Lit::Float(lit) => Ok(Lit::Float(LitFloat::new(&format!("-{}", lit), span))),
+        other => Err(Error::new(other.span(), "expected a number")),
+    }
+}
+
+impl BaeOption {
+    fn name(&self) -> String {
+        self.key.to_string()
+    }
+
+    fn flag(&self) -> bool {
+        if let Some(value) = &self.value {
+            abort!(value.span(), "`{}` does not take a value", self.key);
+        }
+        true
+    }
+
+    fn value(&self) -> &Lit {
+        self.value.as_ref().unwrap_or_else(|| {
+            abort!(
+                self.key.span(),
+                "`{}` requires a value: `{} = ...`",
+                self.key,
+                self.key
+            )
+        })
+    }
+
+    fn str_value(&self) -> LitStr {
+        match self.value() {
+            Lit::Str(lit) => lit.clone(),
+            other => abort!(other.span(), "expected a string literal"),
+        }
+    }
+
+    /// Parses the contents of a string value, as in `key = "Vec<u8>"`.
+    fn parse_str_value<T: Parse>(&self) -> T {
+        let lit = self.str_value();
+        lit.parse()
+            .unwrap_or_else(|err| abort!(lit.span(), "{}", err))
+    }
+
+    fn number_value(&self) -> Lit {
+        match self.value() {
+            lit @ Lit::Int(_) | lit @ Lit::Float(_) => lit.clone(),
+            other => abort!(other.span(), "expected a number"),
+        }
+    }
+}
+
+/// Options given to the struct, as `#[bae(...)]`.
+///
+/// For backwards compatibility a bare string literal, as in `#[bae("name")]`, sets the attribute
+/// name.
+#[derive(Debug, Default)]
+pub(crate) struct StructOptions {
+    pub(crate) name: Option<LitStr>,
+    pub(crate) to_tokens: bool,
+    pub(crate) unknown: UnknownArgs,
+    /// The span of an explicit `unknown = "..."`.
+    pub(crate) unknown_span: Option<proc_macro2::Span>,
+    pub(crate) after_parse: Option<Ident>,
+    pub(crate) examples: Vec<LitStr>,
+    pub(crate) skip_if_no_args: bool,
+    pub(crate) case_insensitive: bool,
+    pub(crate) string_keys: bool,
+    pub(crate) transparent_errors: bool,
+    pub(crate) list: bool,
+    pub(crate) merge: bool,
+    pub(crate) transparent: bool,
+    pub(crate) allow_missing_parens: bool,
+    pub(crate) emit_diagnostics: bool,
+    pub(crate) prefix: Option<LitStr>,
+    pub(crate) suffix: Option<LitStr>,
+    pub(crate) name_prefix: Option<LitStr>,
+    pub(crate) at_least_one_field: bool,
+    pub(crate) keywords: bool,
+    pub(crate) env_prefix: Option<LitStr>,
+    pub(crate) all_optional: bool,
+    pub(crate) try_from: bool,
+}
+
+/// What to do with arguments that don't match any field.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum UnknownArgs {
+    #[default]
+    Ignore,
+    Warn,
+    Error,
+}
+
+enum StructOption {
+    Name(LitStr),
+    Option(BaeOption),
+}
+
+impl Parse for StructOption {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(LitStr) {
+            input.parse().map(StructOption::Name)
+        } else {
+            input.parse().map(StructOption::Option)
+        }
+    }
+}
+
+impl StructOptions {
+    pub(crate) fn from_attrs(attrs: &[Attribute]) -> Self {
+        let mut options = Self::default();
+
+        for option in parse_bae_attrs::<StructOption>(attrs) {
+            match option {
+                StructOption::Name(name) => options.name = Some(name),
+                StructOption::Option(option) => match &*option.name() {
+                    "to_tokens" => options.to_tokens = option.flag(),
+                    "after_parse" => options.after_parse = Some(option.parse_str_value()),
+                    "transparent_errors" => options.transparent_errors = option.flag(),
+                    "list" => options.list = option.flag(),
+                    "merge" => options.merge = option.flag(),
+                    "transparent" => options.transparent = option.flag(),
+                    "allow_missing_parens" => options.allow_missing_parens = option.flag(),
+                    "emit_diagnostics" => options.emit_diagnostics = option.flag(),
+                    "prefix" => options.prefix = Some(option.str_value()),
+                    "suffix" => options.suffix = Some(option.str_value()),
+                    "at_least_one_field" => options.at_least_one_field = option.flag(),
+                    "name_prefix" => options.name_prefix = Some(option.str_value()),
+                    "env_prefix" => options.env_prefix = Some(option.str_value()),
+                    "string_keys" => options.string_keys = option.flag(),
+                    "keywords" => options.keywords = option.flag(),
+                    "all_optional" => options.all_optional = option.flag(),
+                    "try_from" => options.try_from = option.flag(),
+                    "case_insensitive" => options.case_insensitive = option.flag(),
+                    "skip_if_no_args" => options.skip_if_no_args = option.flag(),
+                    "examples" => options.examples.push(option.str_value()),
+                    // The name serde uses for `unknown = "error"`
+                    "deny_unknown_fields" => {
+                        if option.flag() {
+                            options.unknown = UnknownArgs::Error;
+                        }
+                    }
+                    "unknown" => {
+                        let unknown = option.str_value();
+                        options.unknown_span = Some(unknown.span());
+                        options.unknown = match &*unknown.value() {
+                            "ignore" => UnknownArgs::Ignore,
+                            "warn" => UnknownArgs::Warn,
+                            "error" => UnknownArgs::Error,
+                            _ => abort!(
+                                unknown.span(),
+                                "expected `\"ignore\"`, `\"warn\"` or `\"error\"`"
+                            ),
+                        };
+                    }
+                    _ => abort!(option.key.span(), "unknown bae option `{}`", option.key),
+                },
+            }
+        }
+
+        options
+    }
+}
+
+/// What to do when a field's argument isn't given, from `when_absent`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum WhenAbsent {
+    Error,
+    Default,
+    Skip,
+}
+
+/// Options given to a field, as `#[bae(...)]`.
+#[derive(Debug, Default)]
+pub(crate) struct FieldOptions {
+    pub(crate) capture_meta: bool,
+    pub(crate) greedy: bool,
+    pub(crate) required: bool,
+    pub(crate) when_absent: Option<(WhenAbsent, proc_macro2::Span)>,
+    pub(crate) multiple: bool,
+    pub(crate) skip_if_default: bool,
+    pub(crate) min: Option<Lit>,
+    pub(crate) max: Option<Lit>,
+    pub(crate) null: Option<LitStr>,
+    pub(crate) nested_tokens: bool,
+    pub(crate) rename: Option<LitStr>,
+    pub(crate) default: Option<Expr>,
+    pub(crate) parse_quote_default: Option<LitStr>,
+    pub(crate) default_fn: Option<Path>,
+    pub(crate) transparent_errors: bool,
+    pub(crate) meta_list: bool,
+    pub(crate) repeated: bool,
+    pub(crate) rest: bool,
+    pub(crate) conflicts_with: Vec<Ident>,
+    pub(crate) forbidden_with: Vec<LitStr>,
+    pub(crate) list_separator: Option<LitChar>,
+    pub(crate) ignore_case: bool,
+    pub(crate) json: bool,
+    pub(crate) count_range: Option<ExprRange>,
+    pub(crate) prefix: Option<LitStr>,
+    pub(crate) suffix: Option<LitStr>,
+    pub(crate) parse_as: Option<Type>,
+    pub(crate) matched_suffix: bool,
+    pub(crate) byte_str: bool,
+    pub(crate) ident_or_str: bool,
+    pub(crate) flatten: bool,
+    pub(crate) catch_all_switches: bool,
+    pub(crate) include_str: bool,
+    pub(crate) items: bool,
+    pub(crate) trim: bool,
+    pub(crate) single_pat: bool,
+    pub(crate) since: Option<LitStr>,
+    pub(crate) deprecated: bool,
+    pub(crate) lowercase: bool,
+    pub(crate) one_of: Option<LitStr>,
+    pub(crate) uppercase: bool,
+}
+
+impl FieldOptions {
+    pub(crate) fn from_attrs(attrs: &[Attribute]) -> Self {
+        let mut options = Self::default();
+
+        for option in parse_bae_attrs::<BaeOption>(attrs) {
+            match &*option.name() {
+                "capture_meta" => options.capture_meta = option.flag(),
+                "greedy" => options.greedy = option.flag(),
+                "required" => options.required = option.flag(),
+                "when_absent" => {
+                    let when_absent = option.str_value();
+                    let policy = match &*when_absent.value() {
+                        "error" => WhenAbsent::Error,
+                        "default" => WhenAbsent::Default,
+                        "skip" => WhenAbsent::Skip,
+                        _ => abort!(
+                            when_absent.span(),
+                            "expected `\"error\"`, `\"default\"` or `\"skip\"`"
+                        ),
+                    };
+                    options.when_absent = Some((policy, when_absent.span()));
+                }
+                "multiple" => options.multiple = option.flag(),
+                "skip_if_default" => options.skip_if_default = option.flag(),
+                "min" => options.min = Some(option.number_value()),
+                "max" => options.max = Some(option.number_value()),
+                "nested_tokens" => options.nested_tokens = option.flag(),
+                "meta_list" => options.meta_list = option.flag(),
+                "repeated" => options.repeated = option.flag(),
+                "rest" => options.rest = option.flag(),
+                "flatten" => options.flatten = option.flag(),
+                "catch_all_switches" => options.catch_all_switches = option.flag(),
+                "conflicts_with" => options.conflicts_with.push(option.parse_str_value()),
+                "forbidden_with" => options.forbidden_with.push(option.str_value()),
+                "transparent_errors" => options.transparent_errors = option.flag(),
+                "ignore_case" => options.ignore_case = option.flag(),
+                "json" => options.json = option.flag(),
+                "prefix" => options.prefix = Some(option.str_value()),
+                "suffix" => options.suffix = Some(option.str_value()),
+                "byte_str" => options.byte_str = option.flag(),
+                "ident_or_str" => options.ident_or_str = option.flag(),
+                "include_str" => options.include_str = option.flag(),
+                "items" => options.items = option.flag(),
+                "trim" => options.trim = option.flag(),
+                "single_pat" => options.single_pat = option.flag(),
+                "since" => options.since = Some(option.str_value()),
+                "deprecated" => options.deprecated = option.flag(),
+                "lowercase" => options.lowercase = option.flag(),
+                "one_of" => options.one_of = Some(option.str_value()),
+                "uppercase" => options.uppercase = option.flag(),
+                "matched_suffix" => options.matched_suffix = option.flag(),
+                "parse_as" => options.parse_as = Some(option.parse_str_value()),
+                "count_range" => options.count_range = Some(option.parse_str_value()),
+                "default" => options.default = Some(option.parse_str_value()),
+                "default_fn" => options.default_fn = Some(option.parse_str_value()),
+                "parse_quote_default" => options.parse_quote_default = Some(option.str_value()),
+                "rename" => options.rename = Some(option.str_value()),
+                "list_separator" => {
+                    let separator = option.str_value();
+                    let value = separator.value();
+                    let mut chars = value.chars();
+                    match (chars.next(), chars.next()) {
+                        (Some(c), None) if "=<>!~+-*/%^&|@.,;:#$?".contains(c) => {
+                            options.list_separator = Some(LitChar::new(c, separator.span()));
+                        }
+                        _ => abort!(
+                            separator.span(),
+                            "the separator must be a single punctuation character, such as `;`"
+                        ),
+                    }
+                }
+                "null" => {
+                    let null = option.str_value();
+                    if parse_str::<Ident>(&null.value()).is_err() {
+                        abort!(null.span(), "the null keyword must be an identifier");
+                    }
+                    options.null = Some(null);
+                }
+                _ => abort!(option.key.span(), "unknown bae option `{}`", option.key),
+            }
+        }
+
+        options
+    }
+
+    /// The expression used when the argument isn't given, from `default`, `default_fn` or
+    /// `parse_quote_default`.
+    pub(crate) fn default_value(&self) -> Option<proc_macro2::TokenStream> {
+        if let Some(default) = &self.default {
+            return Some(quote::quote!(#default));
+        }
+        if let Some(default_fn) = &self.default_fn {
+            return Some(quote::quote!(#default_fn()));
+        }
+        self.parse_quote_default.as_ref().map(|default| {
+            let tokens = default
+                .parse::<proc_macro2::TokenStream>()
+                .unwrap_or_else(|err| abort!(default.span(), "{}", err));
+            quote::quote_spanned!(default.span()=> syn::parse_quote!(#tokens))
+        })
+    }
+
+    /// The `when_absent` policy, if given.
+    pub(crate) fn when_absent(&self) -> Option<WhenAbsent> {
+        self.when_absent.map(|(policy, _)| policy)
+    }
+
+    /// Whether the argument must be given, through `required` or `when_absent = "error"`.
+    pub(crate) fn is_required(&self) -> bool {
+        self.required || self.when_absent() == Some(WhenAbsent::Error)
+    }
+
+    /// The inclusive bounds of `count_range`, as in `1..=3`, `2..` or `..5`.
+    pub(crate) fn count_bounds(&self) -> Option<(usize, Option<usize>)> {
+        let range = self.count_range.as_ref()?;
+        let bound = |expr: &Option<Box<Expr>>| {
+            expr.as_ref().map(|expr| match &**expr {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Int(lit), ..
+                }) => lit
+                    .base10_parse::<usize>()
+                    .unwrap_or_else(|err| abort!(lit.span(), "{}", err)),
+                other => abort!(other, "expected an integer"),
+            })
+        };
+
+        let min = bound(&range.from).unwrap_or(0);
+        let max = match (bound(&range.to), &range.limits) {
+            (Some(max), RangeLimits::Closed(_)) => Some(max),
+            (Some(0), RangeLimits::HalfOpen(_)) => abort!(range, "the range is empty"),
+            (Some(max), RangeLimits::HalfOpen(_)) => Some(max - 1),
+            (None, _) => None,
+        };
+        if max.is_some_and(|max| max < min) {
+            abort!(range, "the range is empty");
+        }
+        Some((min, max))
+    }
+
+    /// The bare word given for `Some(None)` in `Option<Option<T>>` fields.
+    pub(crate) fn null_keyword(&self) -> String {
+        self.null
+            .as_ref()
+            .map_or_else(|| "none".to_string(), LitStr::value)
+    }
+
+    /// The character separating the values of `Vec` fields.
+    pub(crate) fn list_separator(&self) -> LitChar {
+        self.list_separator
+            .clone()
+            .unwrap_or_else(|| LitChar::new(',', proc_macro2::Span::call_site()))
+    }
+
+    /// Whether the field is parsed from the attribute's arguments.
+    pub(crate) fn is_argument(&self) -> bool {
+        !self.capture_meta
+            && !self.rest
+            && !self.matched_suffix
+            && !self.flatten
+            && !self.catch_all_switches
+    }
+}
+
+fn parse_bae_attrs<T: Parse>(attrs: &[Attribute]) -> Vec<T> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("bae"))
+        .flat_map(|attr| {
+            attr.parse_args_with(Punctuated::<T, Token![,]>::parse_terminated)
+                .unwrap_or_else(|err| abort!(err.span(), "{}", err))
+        })
+        .collect()
+}
@@ -0,0 +1,15 @@
+use better_bae::FromAttributes;
+
+#[derive(FromAttributes)]
+struct MyAttr {
+    #[bae(min = 1)]
+    name: syn::Ident,
+}
+
+#[derive(FromAttributes)]
+struct OtherAttr {
+    #[bae(max = "ten")]
+    count: u8,
+}
+
+fn main() {}
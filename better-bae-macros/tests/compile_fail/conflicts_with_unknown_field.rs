@@ -0,0 +1,9 @@
+use better_bae::FromAttributes;
+
+#[derive(FromAttributes)]
+struct MyAttr {
+    #[bae(conflicts_with = "missing")]
+    a: Option<()>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use better_bae::FromAttributes;
+
+#[derive(FromAttributes)]
+struct MyAttr {
+    #[bae(default = "Some(1)")]
+    value: Option<u8>,
+}
+
+fn main() {}
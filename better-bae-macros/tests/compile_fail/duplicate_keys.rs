@@ -0,0 +1,17 @@
+use better_bae::FromAttributes;
+
+#[derive(FromAttributes)]
+struct MyAttr {
+    foo: syn::Ident,
+
+    #[bae(rename = "foo")]
+    bar: syn::Ident,
+}
+
+#[derive(FromAttributes)]
+struct OtherAttr {
+    #[bae(rename = "not an ident")]
+    foo: syn::Ident,
+}
+
+fn main() {}
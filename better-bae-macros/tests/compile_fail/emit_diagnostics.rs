@@ -0,0 +1,5 @@
+// Every invalid argument is reported, not only the first
+#[better_bae_test_macros::route(path = "/", method = "GET", timeout = -1)]
+fn handler() {}
+
+fn main() {}
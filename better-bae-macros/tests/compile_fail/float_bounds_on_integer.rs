@@ -0,0 +1,9 @@
+use better_bae::FromAttributes;
+
+#[derive(FromAttributes)]
+struct MyAttr {
+    #[bae(max = 0.5)]
+    count: u32,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use better_bae::FromAttributes;
+
+#[derive(FromAttributes)]
+#[bae(to_tokens)]
+struct Table {
+    #[bae(include_str)]
+    schema: String,
+}
+
+fn main() {}
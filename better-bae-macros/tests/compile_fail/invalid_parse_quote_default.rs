@@ -0,0 +1,9 @@
+use better_bae::FromAttributes;
+
+#[derive(FromAttributes)]
+struct MyAttr {
+    #[bae(parse_quote_default = "Vec<")]
+    ty: syn::Type,
+}
+
+fn main() {}
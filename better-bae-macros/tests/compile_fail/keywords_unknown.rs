@@ -0,0 +1,9 @@
+use better_bae::FromAttributes;
+
+#[derive(FromAttributes)]
+#[bae(keywords, unknown = "ignore")]
+struct Kw {
+    path: syn::LitStr,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use better_bae::FromAttributes;
+
+#[derive(FromAttributes)]
+struct Route {
+    #[bae(lowercase, one_of = "get|POST")]
+    method: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use better_bae::FromAttributes;
+
+#[derive(FromAttributes)]
+struct MyAttr {
+    #[bae(skip_if_default)]
+    name: syn::Ident,
+}
+
+fn main() {}
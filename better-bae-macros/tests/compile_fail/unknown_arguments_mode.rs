@@ -0,0 +1,9 @@
+use better_bae::FromAttributes;
+
+#[derive(FromAttributes)]
+#[bae(unknown = "panic")]
+struct MyAttr {
+    name: syn::Ident,
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+use better_bae::FromAttributes;
+
+#[derive(FromAttributes)]
+struct MyAttr {
+    #[bae(not_an_option)]
+    name: syn::Ident,
+}
+
+#[derive(FromAttributes)]
+#[bae(not_an_option)]
+struct OtherAttr {
+    name: syn::Ident,
+}
+
+#[derive(FromAttributes)]
+struct FlagAttr {
+    #[bae(capture_meta = "yes")]
+    meta: better_bae::AttrMeta,
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+use better_bae::FromAttributes;
+
+#[derive(FromAttributes)]
+struct MyAttr {
+    #[bae(when_absent = "skip")]
+    name: syn::Ident,
+}
+
+#[derive(FromAttributes)]
+struct OtherAttr {
+    #[bae(when_absent = "default")]
+    name: Option<syn::Ident>,
+}
+
+#[derive(FromAttributes)]
+struct ThirdAttr {
+    #[bae(when_absent = "sometimes")]
+    name: Option<syn::Ident>,
+}
+
+fn main() {}
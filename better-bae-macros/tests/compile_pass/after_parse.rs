@@ -0,0 +1,78 @@
+use better_bae::{FromAttributes, TryFromAttributes};
+use syn::parse::ParseStream;
+
+#[derive(Debug, FromAttributes)]
+#[bae(after_parse = "consume_extra")]
+struct MyAttr {
+    name: syn::Ident,
+    switch: Option<()>,
+
+    #[bae(capture_meta)]
+    meta: better_bae::AttrMeta,
+}
+
+impl MyAttr {
+    fn consume_extra(&mut self, input: ParseStream) -> syn::Result<()> {
+        if input.parse::<Option<syn::Token![;]>>()?.is_some() {
+            let extra = input.parse::<syn::LitStr>()?;
+            self.name = syn::Ident::new(&extra.value(), extra.span());
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, FromAttributes)]
+#[bae(after_parse = "consume_rest")]
+struct Hooked {
+    e: syn::Expr,
+    rest: Option<syn::Ident>,
+}
+
+impl Hooked {
+    fn consume_rest(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<syn::Token![;]>()?;
+        self.rest = Some(input.parse()?);
+        Ok(())
+    }
+}
+
+fn parse(tokens: proc_macro2::TokenStream) -> syn::Result<MyAttr> {
+    MyAttr::from_tokens(tokens)
+}
+
+fn main() {
+    let attr = parse(quote::quote! { name = foo, switch }).unwrap();
+    assert_eq!(attr.name, "foo");
+    assert!(attr.switch.is_some());
+
+    let attr = parse(quote::quote! { name = foo; "bar" }).unwrap();
+    assert_eq!(attr.name, "bar");
+    assert!(attr.switch.is_none());
+
+    let attr = parse(quote::quote! { switch, name = foo, ; "baz" }).unwrap();
+    assert_eq!(attr.name, "baz");
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(name = foo; "qux")]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.name, "qux");
+
+    // Mandatory fields are checked before the hook runs
+    let err = parse(quote::quote! { ; "bar" }).unwrap_err();
+    assert!(err.to_string().ends_with("`#[my_attr]` is missing `name` argument"));
+
+    // An expression ends where its own parser stops, as anything may follow the arguments
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[hooked(e = 1 + 2; foo)]
+        struct Foo;
+    };
+    let hooked = Hooked::from_attributes(&item.attrs).unwrap();
+    assert_eq!(hooked.e, syn::parse_quote!(1 + 2));
+    assert_eq!(hooked.rest.unwrap(), "foo");
+
+    // The hook must consume the rest of the input
+    let err = parse(quote::quote! { name = foo; "bar" extra }).unwrap_err();
+    assert_eq!(err.to_string(), "unexpected token");
+}
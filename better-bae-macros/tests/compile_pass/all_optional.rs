@@ -0,0 +1,44 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, FromAttributes)]
+#[bae(all_optional)]
+struct MyAttr {
+    name: String,
+    retries: u8,
+    tags: Vec<syn::Ident>,
+    #[bae(default = "30")]
+    timeout: u32,
+    comment: Option<String>,
+    #[bae(required)]
+    path: syn::LitStr,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(path = "/")]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.name, "");
+    assert_eq!(attr.retries, 0);
+    assert!(attr.tags.is_empty());
+    assert_eq!(attr.timeout, 30);
+    assert_eq!(attr.comment, None);
+    assert_eq!(attr.path.value(), "/");
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(name = "api", retries = 3, timeout = 5, path = "/api")]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.name, "api");
+    assert_eq!(attr.retries, 3);
+    assert_eq!(attr.timeout, 5);
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(name = "api")]
+        struct Foo;
+    };
+    let err = MyAttr::from_attributes(&item.attrs).unwrap_err();
+    assert!(err.to_string().ends_with("`#[my_attr]` is missing `path` argument"));
+}
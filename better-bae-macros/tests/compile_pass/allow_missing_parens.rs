@@ -0,0 +1,48 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, FromAttributes)]
+#[bae(allow_missing_parens)]
+struct Lenient {
+    name: Option<syn::Ident>,
+}
+
+#[derive(Debug, FromAttributes)]
+#[bae(allow_missing_parens)]
+struct LenientRequired {
+    name: syn::Ident,
+}
+
+#[derive(Debug, FromAttributes)]
+struct Strict {
+    name: Option<syn::Ident>,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[lenient]
+        #[lenient_required]
+        #[strict]
+        struct Foo;
+    };
+    let attr = Lenient::from_attributes(&item.attrs).unwrap();
+    assert!(attr.name.is_none());
+
+    let err = LenientRequired::from_attributes(&item.attrs).unwrap_err();
+    assert!(err
+        .to_string()
+        .ends_with("`#[lenient_required]` is missing `name` argument"));
+
+    let err = Strict::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "`#[strict]` requires arguments in parentheses, as in `#[strict(...)]`"
+    );
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[lenient(name = foo)]
+        #[strict(name = foo)]
+        struct Foo;
+    };
+    assert!(Lenient::from_attributes(&item.attrs).unwrap().name.is_some());
+    assert!(Strict::from_attributes(&item.attrs).unwrap().name.is_some());
+}
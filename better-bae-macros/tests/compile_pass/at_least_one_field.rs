@@ -0,0 +1,36 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, FromAttributes)]
+#[bae(at_least_one_field)]
+struct Cache {
+    ttl: Option<u32>,
+    key: Option<syn::LitStr>,
+    #[bae(rename = "no_store")]
+    skip: Option<()>,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[cache(no_store)]
+        struct Foo;
+    };
+    assert!(Cache::from_attributes(&item.attrs).unwrap().skip.is_some());
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[cache()]
+        struct Foo;
+    };
+    let err = Cache::from_attributes(&item.attrs).unwrap_err();
+    assert!(
+        err.to_string()
+            .ends_with("`#[cache]` requires at least one of `ttl`, `key`, `no_store`"),
+        "{}",
+        err
+    );
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[cache(unknown = 1)]
+        struct Foo;
+    };
+    assert!(Cache::from_attributes(&item.attrs).is_err());
+}
@@ -0,0 +1,20 @@
+use better_bae::{FromAttributes, TryFromAttributes};
+
+#[derive(Debug, FromAttributes)]
+struct MyAttr {
+    switch: Option<()>,
+}
+
+#[derive(Debug, FromAttributes)]
+#[bae("renamed")]
+struct Other {
+    switch: Option<()>,
+}
+
+const NAMES: [&str; 2] = [MyAttr::ATTR_NAME, <Other as TryFromAttributes>::ATTR_NAME];
+
+fn main() {
+    assert_eq!(NAMES, ["my_attr", "renamed"]);
+    assert_eq!(MyAttr::attr_name(), MyAttr::ATTR_NAME);
+    assert_eq!(Other::attr_name(), "renamed");
+}
@@ -0,0 +1,41 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, FromAttributes)]
+struct Forward {
+    attr: syn::Attribute,
+    other: Option<syn::Attribute>,
+    name: Option<syn::Ident>,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[forward(attr = #[derive(Debug, Clone)], name = foo)]
+        struct Foo;
+    };
+    let attr = Forward::from_attributes(&item.attrs).unwrap();
+    assert!(attr.attr.path.is_ident("derive"));
+    assert_eq!(attr.attr.tokens.to_string(), "(Debug , Clone)");
+    assert!(attr.other.is_none());
+    assert_eq!(attr.name.unwrap(), "foo");
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[forward(attr = #[a], other = #[b = "c"])]
+        struct Foo;
+    };
+    let attr = Forward::from_attributes(&item.attrs).unwrap();
+    assert!(attr.other.unwrap().path.is_ident("b"));
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[forward(attr = #[a] #[b])]
+        struct Foo;
+    };
+    let err = Forward::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "invalid `attr`: expected a single attribute");
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[forward(attr = foo)]
+        struct Foo;
+    };
+    let err = Forward::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "invalid `attr`: expected an attribute");
+}
@@ -0,0 +1,56 @@
+use better_bae::{FromAttributes, TryFromAttributes};
+
+#[derive(Debug, FromAttributes)]
+struct Route {
+    path: syn::LitStr,
+}
+
+fn main() {
+    let item: syn::ItemTrait = syn::parse_quote! {
+        trait Api {
+            #[route(path = "/users")]
+            fn users(&self);
+
+            const VERSION: u32;
+        }
+    };
+    let route = Route::from_attrs_of(&item.items[0]).unwrap();
+    assert_eq!(route.path.value(), "/users");
+    assert!(Route::try_from_attrs_of(&item.items[1]).unwrap().is_none());
+
+    let item: syn::ItemImpl = syn::parse_quote! {
+        impl Api for Server {
+            #[route(path = "/posts")]
+            fn posts(&self) {}
+        }
+    };
+    let route = Route::from_attrs_of(&item.items[0]).unwrap();
+    assert_eq!(route.path.value(), "/posts");
+    if let syn::ImplItem::Method(method) = &item.items[0] {
+        assert!(Route::try_from_attrs_of(method).unwrap().is_some());
+    }
+
+    let item: syn::Item = syn::parse_quote! {
+        #[route(path = "/")]
+        fn index() {}
+    };
+    assert!(Route::try_from_attrs_of(&item).unwrap().is_some());
+
+    let input: syn::DeriveInput = syn::parse_quote! {
+        #[route(path = "/enum")]
+        enum Pages {
+            #[route(path = "/home")]
+            Home {
+                #[route(path = "/field")]
+                id: u32,
+            },
+        }
+    };
+    assert_eq!(Route::from_attrs_of(&input).unwrap().path.value(), "/enum");
+    if let syn::Data::Enum(data) = &input.data {
+        let variant = &data.variants[0];
+        assert_eq!(Route::from_attrs_of(variant).unwrap().path.value(), "/home");
+        let field = variant.fields.iter().next().unwrap();
+        assert_eq!(Route::from_attrs_of(field).unwrap().path.value(), "/field");
+    }
+}
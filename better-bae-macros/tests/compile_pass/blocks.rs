@@ -0,0 +1,27 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, FromAttributes)]
+struct MyAttr {
+    body: syn::Block,
+    fallback: Option<syn::Block>,
+    name: Option<syn::Ident>,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(body = { let x = 1; x + 1 }, name = foo)]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.body.stmts.len(), 2);
+    assert!(attr.fallback.is_none());
+    assert_eq!(attr.name.unwrap(), "foo");
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(body = { call(a, b, c) }, fallback = {})]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.body.stmts.len(), 1);
+    assert!(attr.fallback.unwrap().stmts.is_empty());
+}
@@ -0,0 +1,38 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, PartialEq, FromAttributes)]
+#[bae(to_tokens)]
+struct MyAttr {
+    #[bae(byte_str)]
+    magic: Vec<u8>,
+    #[bae(byte_str)]
+    header: Option<Vec<u8>>,
+    bytes: Vec<u8>,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(magic = b"abc", header = b"\x00\xff", bytes = [1, 2])]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.magic, b"abc");
+    assert_eq!(attr.header.as_deref(), Some(&[0, 255][..]));
+    assert_eq!(attr.bytes, [1, 2]);
+
+    let tokens = quote::quote!(#attr).to_string();
+    assert_eq!(syn::parse_str::<MyAttr>(&tokens).unwrap(), attr);
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(magic = "abc")]
+        struct Foo;
+    };
+    let err = MyAttr::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "invalid `magic`: expected byte string literal");
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr()]
+        struct Foo;
+    };
+    assert!(MyAttr::from_attributes(&item.attrs).unwrap().magic.is_empty());
+}
@@ -0,0 +1,31 @@
+use better_bae::{AttrMeta, FromAttributes};
+
+#[derive(Debug, FromAttributes)]
+struct MyAttr {
+    name: syn::Ident,
+
+    #[bae(capture_meta)]
+    meta: AttrMeta,
+}
+
+fn main() {
+    let outer: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(name = foo)]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&outer.attrs).unwrap();
+    assert_eq!(attr.name, "foo");
+    assert!(attr.meta.path.is_ident("my_attr"));
+    assert_eq!(attr.meta.style, syn::AttrStyle::Outer);
+
+    let inner: syn::ItemMod = syn::parse_quote! {
+        mod foo {
+            #![my_attr(name = bar)]
+        }
+    };
+    let attr = MyAttr::from_attributes(&inner.attrs).unwrap();
+    assert!(matches!(attr.meta.style, syn::AttrStyle::Inner(_)));
+
+    let parsed = syn::parse_str::<MyAttr>("(name = baz)").unwrap();
+    assert_eq!(parsed.meta, AttrMeta::default());
+}
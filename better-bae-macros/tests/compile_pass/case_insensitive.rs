@@ -0,0 +1,34 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, FromAttributes)]
+#[bae(case_insensitive)]
+struct MyAttr {
+    mandatory_ident: syn::Ident,
+    #[bae(rename = "Label")]
+    name: Option<syn::LitStr>,
+    switch: Option<()>,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(MandatoryIdent = foo, label = "x", SWITCH)]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.mandatory_ident, "foo");
+    assert_eq!(attr.name.unwrap().value(), "x");
+    assert!(attr.switch.is_some());
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(mandatory_ident = foo)]
+        struct Foo;
+    };
+    assert!(MyAttr::from_attributes(&item.attrs).is_ok());
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(mandatory_ident = foo, MandatoryIdent = bar)]
+        struct Foo;
+    };
+    let err = MyAttr::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "duplicate `mandatory_ident` argument");
+}
@@ -0,0 +1,48 @@
+use better_bae::{FromAttributes, TryFromAttributes};
+
+#[derive(Debug, FromAttributes)]
+#[bae(to_tokens)]
+struct Lint {
+    level: Option<syn::Ident>,
+    #[bae(catch_all_switches)]
+    flags: Vec<syn::Ident>,
+    #[bae(rest)]
+    options: Vec<(syn::Ident, syn::Lit)>,
+}
+
+#[derive(Debug, FromAttributes)]
+#[bae(unknown = "error")]
+struct Strict {
+    #[bae(catch_all_switches)]
+    flags: Vec<syn::Ident>,
+}
+
+fn main() {
+    let attr = Lint::from_tokens(quote::quote! {
+        pedantic,
+        level = warn,
+        max_width = 100,
+        r#unsafe,
+    })
+    .unwrap();
+    assert_eq!(attr.level.as_ref().unwrap(), "warn");
+    assert_eq!(attr.flags.len(), 2);
+    assert_eq!(attr.flags[0], "pedantic");
+    assert_eq!(attr.flags[1], "unsafe");
+    assert_eq!(attr.options.len(), 1);
+    assert_eq!(attr.options[0].0, "max_width");
+
+    let tokens = quote::quote!(#attr).to_string();
+    assert_eq!(
+        tokens,
+        quote::quote!((level = warn, max_width = 100, pedantic, unsafe,)).to_string()
+    );
+
+    let err = Lint::from_tokens(quote::quote! { pedantic, pedantic }).unwrap_err();
+    assert_eq!(err.to_string(), "duplicate `pedantic` argument");
+
+    let attr = Strict::from_tokens(quote::quote! { a, b }).unwrap();
+    assert_eq!(attr.flags.len(), 2);
+    let err = Strict::from_tokens(quote::quote! { a, b = 1 }).unwrap_err();
+    assert_eq!(err.to_string(), "unknown argument `b`");
+}
@@ -0,0 +1,50 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, FromAttributes)]
+struct OnEvent {
+    handler: syn::ExprClosure,
+    fallback: Option<syn::Expr>,
+    #[bae(greedy)]
+    filter: Option<syn::Expr>,
+    priority: Option<u8>,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[on_event(handler = |e| process(e))]
+        struct Foo;
+    };
+    let attr = OnEvent::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.handler, syn::parse_quote!(|e| process(e)));
+
+    // The commas between the parameters don't end the value
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[on_event(
+            handler = |a, b: &str| process(a, b),
+            fallback = |a, b| a + b,
+            filter = |(a, b), c| a < b && b > c,
+            priority = 2,
+        )]
+        struct Foo;
+    };
+    let attr = OnEvent::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.handler, syn::parse_quote!(|a, b: &str| process(a, b)));
+    assert_eq!(attr.fallback, Some(syn::parse_quote!(|a, b| a + b)));
+    assert_eq!(attr.filter, Some(syn::parse_quote!(|(a, b), c| a < b && b > c)));
+    assert_eq!(attr.priority, Some(2));
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[on_event(handler = move |e| { process(e); }, priority = 1)]
+        struct Foo;
+    };
+    let attr = OnEvent::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.handler, syn::parse_quote!(move |e| { process(e); }));
+    assert_eq!(attr.priority, Some(1));
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[on_event(handler = process)]
+        struct Foo;
+    };
+    let err = OnEvent::from_attributes(&item.attrs).unwrap_err();
+    assert!(err.to_string().starts_with("invalid `handler`: "));
+}
@@ -0,0 +1,39 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, FromAttributes)]
+struct Serde {
+    #[bae(conflicts_with = "skip", conflicts_with = "default")]
+    rename: Option<syn::LitStr>,
+    skip: Option<()>,
+    #[bae(conflicts_with = "skip")]
+    default: Option<syn::Path>,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[serde(rename = "a")]
+        struct Foo;
+    };
+    assert!(Serde::from_attributes(&item.attrs).is_ok());
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[serde(rename = "a", skip)]
+        struct Foo;
+    };
+    let err = Serde::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "`skip` can't be used together with `rename`");
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[serde(skip, rename = "a")]
+        struct Foo;
+    };
+    let err = Serde::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "`rename` can't be used together with `skip`");
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[serde(default = my_default, skip)]
+        struct Foo;
+    };
+    let err = Serde::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "`skip` can't be used together with `default`");
+}
@@ -0,0 +1,56 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, FromAttributes)]
+struct Route {
+    #[bae(count_range = "1..=3")]
+    method: Vec<syn::Ident>,
+    #[bae(count_range = "..2")]
+    tags: Vec<syn::LitStr>,
+    #[bae(count_range = "2..")]
+    hosts: Vec<syn::LitStr>,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[route(method = [GET, POST], tags = ["a"], hosts = ["a", "b", "c"])]
+        struct Foo;
+    };
+    let attr = Route::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.method.len(), 2);
+    assert_eq!(attr.hosts.len(), 3);
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[route(method = [GET, POST, PUT, DELETE])]
+        struct Foo;
+    };
+    let err = Route::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "expected 1 to 3 `method` entries, found 4");
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[route(method = [])]
+        struct Foo;
+    };
+    let err = Route::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "expected 1 to 3 `method` entries, found 0");
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[route(tags = ["a"])]
+        struct Foo;
+    };
+    let err = Route::from_attributes(&item.attrs).unwrap_err();
+    assert!(err.to_string().ends_with("expected 1 to 3 `method` entries, found 0"));
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[route(method = [GET], tags = ["a", "b"])]
+        struct Foo;
+    };
+    let err = Route::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "expected at most 1 `tags` entries, found 2");
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[route(method = [GET], hosts = ["a"])]
+        struct Foo;
+    };
+    let err = Route::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "expected at least 2 `hosts` entries, found 1");
+}
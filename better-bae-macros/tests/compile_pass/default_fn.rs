@@ -0,0 +1,37 @@
+use better_bae::FromAttributes;
+
+fn default_methods() -> Vec<syn::Ident> {
+    vec![syn::parse_quote!(GET), syn::parse_quote!(HEAD)]
+}
+
+mod defaults {
+    pub fn port() -> u16 {
+        8080
+    }
+}
+
+#[derive(Debug, FromAttributes)]
+struct Route {
+    #[bae(default_fn = "default_methods")]
+    methods: Vec<syn::Ident>,
+    #[bae(default_fn = "defaults::port")]
+    port: u16,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[route(port = 80)]
+        struct Foo;
+    };
+    let attr = Route::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.methods, ["GET", "HEAD"]);
+    assert_eq!(attr.port, 80);
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[route(methods = [POST])]
+        struct Foo;
+    };
+    let attr = Route::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.methods, ["POST"]);
+    assert_eq!(attr.port, 8080);
+}
@@ -0,0 +1,36 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, FromAttributes)]
+struct MyAttr {
+    name: syn::Ident,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr[name = brackets]]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.name, "brackets");
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr{name = braces}]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.name, "braces");
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(name = parens)]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.name, "parens");
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr = "name"]
+        struct Foo;
+    };
+    let err = MyAttr::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "expected parentheses");
+}
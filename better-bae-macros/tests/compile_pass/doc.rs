@@ -0,0 +1,26 @@
+use better_bae::FromAttributes;
+
+/// Marks a route handler.
+///
+///  Indented line.
+#[derive(Debug, FromAttributes)]
+#[doc = "Usage: `#[route(path = \"/\")]`"]
+struct Route {
+    /// Not part of the struct's documentation
+    path: syn::LitStr,
+}
+
+#[derive(Debug, FromAttributes)]
+struct Undocumented {
+    path: syn::LitStr,
+}
+
+const ROUTE_DOC: &str = Route::DOC;
+
+fn main() {
+    assert_eq!(
+        ROUTE_DOC,
+        "Marks a route handler.\n\n Indented line.\nUsage: `#[route(path = \"/\")]`"
+    );
+    assert_eq!(Undocumented::DOC, "");
+}
@@ -0,0 +1,33 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, FromAttributes)]
+struct MyAttr {
+    name: Option<syn::Ident>,
+    switch: Option<()>,
+
+    #[bae(multiple)]
+    last: Option<syn::Ident>,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(last = a, last = b, switch)]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.last.unwrap(), "b");
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(name = a, name = b)]
+        struct Foo;
+    };
+    let err = MyAttr::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "duplicate `name` argument");
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(switch, switch)]
+        struct Foo;
+    };
+    let err = MyAttr::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "duplicate `switch` argument");
+}
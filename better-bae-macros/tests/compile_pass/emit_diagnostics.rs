@@ -0,0 +1,26 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, FromAttributes)]
+#[bae(emit_diagnostics)]
+struct MyAttr {
+    name: syn::LitStr,
+    count: Option<u32>,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(name = "a", count = 3)]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.name.value(), "a");
+    assert_eq!(attr.count, Some(3));
+
+    // The invalid argument is skipped and its error returned, instead of the missing `name`
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(count = "x" y z)]
+        struct Foo;
+    };
+    let err = MyAttr::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "invalid `count`: expected integer literal");
+}
@@ -0,0 +1,30 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, FromAttributes)]
+struct MyAttr {
+    name: Option<String>,
+    lit: Option<syn::LitStr>,
+    #[bae(trim)]
+    trimmed: Option<String>,
+}
+
+fn main() {
+    // An empty string is a value like any other, distinct from a missing argument
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(name = "", lit = "", trimmed = "   ")]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.name.as_deref(), Some(""));
+    assert_eq!(attr.lit.unwrap().value(), "");
+    assert_eq!(attr.trimmed.as_deref(), Some(""));
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr()]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.name, None);
+    assert!(attr.lit.is_none());
+    assert_eq!(attr.trimmed, None);
+}
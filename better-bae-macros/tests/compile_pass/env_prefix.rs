@@ -0,0 +1,101 @@
+use better_bae::{FromAttributes, TryFromAttributes};
+
+#[derive(Debug, FromAttributes)]
+#[bae(env_prefix = "BAE_TEST_SERVER_")]
+struct Server {
+    host: syn::LitStr,
+    max_age: Option<u32>,
+    #[bae(default = "8080")]
+    port: u16,
+    debug: Option<()>,
+}
+
+#[derive(Debug, FromAttributes)]
+#[bae(env_prefix = "BAE_TEST_PROBE_")]
+struct Probe {
+    #[bae(min = 1, max = 10)]
+    level: Option<u8>,
+    #[bae(count_range = "..=2")]
+    targets: Vec<syn::Ident>,
+    #[bae(lowercase, one_of = "a|b")]
+    mode: Option<String>,
+}
+
+fn main() {
+    let err = Server::from_tokens(quote::quote! {}).unwrap_err();
+    assert!(
+        err.to_string().ends_with("`#[server]` is missing `host` argument"),
+        "{}",
+        err
+    );
+
+    // Values are tokens, string literals need their quotes
+    std::env::set_var("BAE_TEST_SERVER_HOST", "localhost");
+    let err = Server::from_tokens(quote::quote! {}).unwrap_err();
+    assert!(
+        err.to_string()
+            .starts_with("invalid `BAE_TEST_SERVER_HOST` environment variable: "),
+        "{}",
+        err
+    );
+
+    std::env::set_var("BAE_TEST_SERVER_HOST", "\"localhost\"");
+    std::env::set_var("BAE_TEST_SERVER_MAX_AGE", "60");
+    std::env::set_var("BAE_TEST_SERVER_DEBUG", "1");
+    let attr = Server::from_tokens(quote::quote! {}).unwrap();
+    assert_eq!(attr.host.value(), "localhost");
+    assert_eq!(attr.max_age, Some(60));
+    assert_eq!(attr.port, 8080);
+    assert!(attr.debug.is_none());
+
+    // Arguments take precedence over the environment, which takes precedence over defaults
+    std::env::set_var("BAE_TEST_SERVER_PORT", "3000");
+    let attr = Server::from_tokens(quote::quote! { host = "example.com" }).unwrap();
+    assert_eq!(attr.host.value(), "example.com");
+    assert_eq!(attr.port, 3000);
+
+    std::env::set_var("BAE_TEST_SERVER_PORT", "http");
+    let err = Server::from_tokens(quote::quote! {}).unwrap_err();
+    assert!(
+        err.to_string()
+            .starts_with("invalid `BAE_TEST_SERVER_PORT` environment variable: "),
+        "{}",
+        err
+    );
+
+    // Values from the environment are validated like arguments
+    std::env::set_var("BAE_TEST_PROBE_LEVEL", "200");
+    let err = Probe::from_tokens(quote::quote! {}).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid `BAE_TEST_PROBE_LEVEL` environment variable: `level` must be between 1 and 10"
+    );
+    let attr = Probe::from_tokens(quote::quote! { level = 3 }).unwrap();
+    assert_eq!(attr.level, Some(3));
+
+    std::env::set_var("BAE_TEST_PROBE_LEVEL", "5");
+    std::env::set_var("BAE_TEST_PROBE_TARGETS", "[a, b, c]");
+    let err = Probe::from_tokens(quote::quote! {}).unwrap_err();
+    assert!(
+        err.to_string()
+            .starts_with("invalid `BAE_TEST_PROBE_TARGETS` environment variable: "),
+        "{}",
+        err
+    );
+
+    std::env::set_var("BAE_TEST_PROBE_TARGETS", "[a, b]");
+    let attr = Probe::from_tokens(quote::quote! {}).unwrap();
+    assert_eq!(attr.level, Some(5));
+    assert_eq!(attr.targets, ["a", "b"]);
+
+    std::env::set_var("BAE_TEST_PROBE_MODE", "\"zzz\"");
+    let err = Probe::from_tokens(quote::quote! {}).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid `BAE_TEST_PROBE_MODE` environment variable: `mode` must be one of: `a`, `b`"
+    );
+
+    std::env::set_var("BAE_TEST_PROBE_MODE", "\"B\"");
+    let attr = Probe::from_tokens(quote::quote! {}).unwrap();
+    assert_eq!(attr.mode.as_deref(), Some("b"));
+}
@@ -0,0 +1,49 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, FromAttributes)]
+#[bae(examples = "my_attr(path = \"x\", flag)")]
+#[bae(examples = "my_attr(path = \"x\")")]
+struct MyAttr {
+    path: syn::LitStr,
+    flag: Option<()>,
+}
+
+#[derive(Debug, FromAttributes)]
+struct Plain {
+    path: syn::LitStr,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(path = "x", flag)]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.path.value(), "x");
+    assert!(attr.flag.is_some());
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(flag)]
+        struct Foo;
+    };
+    let err = MyAttr::from_attributes(&item.attrs).unwrap_err();
+    assert!(err.to_string().ends_with(
+        "`#[my_attr]` is missing `path` argument\n\
+         example: my_attr(path = \"x\", flag)\n\
+         example: my_attr(path = \"x\")"
+    ));
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr = "x"]
+        struct Foo;
+    };
+    let err = MyAttr::from_attributes(&item.attrs).unwrap_err();
+    assert!(err.to_string().contains("\nexample: my_attr(path = \"x\", flag)"));
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[plain]
+        struct Foo;
+    };
+    let err = Plain::from_attributes(&item.attrs).unwrap_err();
+    assert!(!err.to_string().contains("example"));
+}
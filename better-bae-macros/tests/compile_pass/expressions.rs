@@ -0,0 +1,66 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, FromAttributes)]
+struct MyAttr {
+    value: syn::Expr,
+    other: Option<syn::Expr>,
+    flag: Option<()>,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(value = 1 << 4, flag)]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.value, syn::parse_quote!(1 << 4));
+    assert!(attr.flag.is_some());
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(value = f(a, b) + g(c, [d, e]), other = -N * 2, flag)]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.value, syn::parse_quote!(f(a, b) + g(c, [d, e])));
+    assert_eq!(attr.other, Some(syn::parse_quote!(-N * 2)));
+
+    // Comparisons aren't generic arguments, so `<` and `>` never hold a `,` inside them
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(value = a > b && c < d, flag)]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.value, syn::parse_quote!(a > b && c < d));
+    assert!(attr.flag.is_some());
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(value = a < b, other = c > d, flag)]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.value, syn::parse_quote!(a < b));
+    assert_eq!(attr.other, Some(syn::parse_quote!(c > d)));
+    assert!(attr.flag.is_some());
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(value = x >= Vec::<u8>::new().len(), other = (a < b) == (c > d))]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.value, syn::parse_quote!(x >= Vec::<u8>::new().len()));
+    assert_eq!(attr.other, Some(syn::parse_quote!((a < b) == (c > d))));
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(value = 1 2, flag)]
+        struct Foo;
+    };
+    let err = MyAttr::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "invalid `value`: unexpected token");
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(value = , flag)]
+        struct Foo;
+    };
+    let err = MyAttr::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "invalid `value`: expected a value");
+}
@@ -0,0 +1,49 @@
+use better_bae::{FieldInfo, FromAttributes};
+
+#[derive(Debug, FromAttributes)]
+struct Route {
+    /// The path the route matches.
+    ///
+    /// Must start with `/`.
+    path: syn::LitStr,
+
+    #[bae(since = "1.2.0", rename = "timeout_secs")]
+    timeout: Option<u32>,
+
+    #[bae(deprecated, since = "0.9.0")]
+    cors: Option<()>,
+}
+
+fn main() {
+    assert_eq!(
+        Route::FIELDS,
+        &[
+            FieldInfo {
+                name: "path",
+                doc: "The path the route matches.\n\nMust start with `/`.",
+                since: None,
+                deprecated: false,
+            },
+            FieldInfo {
+                name: "timeout_secs",
+                doc: "",
+                since: Some("1.2.0"),
+                deprecated: false,
+            },
+            FieldInfo {
+                name: "cors",
+                doc: "",
+                since: Some("0.9.0"),
+                deprecated: true,
+            },
+        ]
+    );
+
+    // The options don't change how the attribute parses.
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[route(path = "/", cors)]
+        struct Foo;
+    };
+    let route = Route::from_attributes(&item.attrs).unwrap();
+    assert!(route.cors.is_some());
+}
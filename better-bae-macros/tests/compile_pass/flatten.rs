@@ -0,0 +1,88 @@
+use better_bae::{FromAttributes, TryFromAttributes};
+
+#[derive(Debug, PartialEq, FromAttributes)]
+#[bae(to_tokens)]
+struct Cors {
+    origin: syn::LitStr,
+    max_age: Option<u32>,
+}
+
+#[derive(Debug, FromAttributes)]
+#[bae(to_tokens)]
+struct Route {
+    path: syn::LitStr,
+    #[bae(flatten)]
+    cors: Option<Cors>,
+}
+
+#[derive(Debug, Default, FromAttributes)]
+struct Common {
+    skip: Option<()>,
+}
+
+#[derive(Debug, FromAttributes)]
+#[bae(unknown = "error")]
+struct Column {
+    name: Option<syn::Ident>,
+    #[bae(flatten)]
+    common: Common,
+}
+
+#[derive(Debug, FromAttributes)]
+#[bae(unknown = "error")]
+struct StrictRoute {
+    path: syn::LitStr,
+    #[bae(flatten)]
+    cors: Option<Cors>,
+}
+
+fn main() {
+    let route = Route::from_tokens(quote::quote! { path = "/" }).unwrap();
+    assert_eq!(route.path.value(), "/");
+    assert!(route.cors.is_none());
+
+    let route =
+        Route::from_tokens(quote::quote! { origin = "*", path = "/", max_age = 60 }).unwrap();
+    let cors = route.cors.as_ref().unwrap();
+    assert_eq!(cors.origin.value(), "*");
+    assert_eq!(cors.max_age, Some(60));
+
+    let tokens = quote::quote!(#route).to_string();
+    assert_eq!(
+        tokens,
+        quote::quote!((path = "/", origin = "*", max_age = 60u32,)).to_string()
+    );
+
+    // Any of its keys makes the flattened struct present, so its required fields must be given.
+    // The error names the attribute they're written in
+    let err = Route::from_tokens(quote::quote! { path = "/", max_age = 60 }).unwrap_err();
+    assert!(
+        err.to_string().ends_with("`#[route]` is missing `origin` argument"),
+        "{}",
+        err
+    );
+
+    // Keys of neither struct don't make it present, they're unknown to the outer one
+    let route = Route::from_tokens(quote::quote! { path = "/", orign = "*" }).unwrap();
+    assert!(route.cors.is_none());
+    assert!(!Cors::accepts_key("orign"));
+    assert!(Cors::accepts_key("max_age"));
+    assert!(Route::accepts_key("origin"));
+
+    let column = Column::from_tokens(quote::quote! { skip, name = id }).unwrap();
+    assert_eq!(column.name.unwrap(), "id");
+    assert!(column.common.skip.is_some());
+
+    let column = Column::from_tokens(quote::quote! {}).unwrap();
+    assert!(column.common.skip.is_none());
+
+    let err = Column::from_tokens(quote::quote! { other = 1 }).unwrap_err();
+    assert_eq!(err.to_string(), "unknown argument `other`");
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[strict_route(path = "/", orign = "*")]
+        struct Foo;
+    };
+    let err = StrictRoute::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "unknown argument `orign`");
+}
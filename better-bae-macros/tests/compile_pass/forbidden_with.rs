@@ -0,0 +1,25 @@
+use better_bae::{FromAttributes, TryFromAttributes};
+
+#[derive(Debug, FromAttributes)]
+struct Handler {
+    #[bae(forbidden_with = "no-std")]
+    thread_local: Option<()>,
+    #[bae(forbidden_with = "no-std", forbidden_with = "wasm")]
+    blocking: Option<()>,
+    name: Option<syn::Ident>,
+}
+
+fn main() {
+    let attr = Handler::from_tokens(quote::quote! { thread_local, blocking }).unwrap();
+    assert!(attr.thread_local.is_some());
+
+    std::env::set_var("CARGO_FEATURE_WASM", "1");
+    let attr = Handler::from_tokens(quote::quote! { thread_local, name = foo }).unwrap();
+    assert_eq!(attr.name.unwrap(), "foo");
+    let err = Handler::from_tokens(quote::quote! { blocking }).unwrap_err();
+    assert_eq!(err.to_string(), "`blocking` can't be used with the `wasm` feature");
+
+    std::env::set_var("CARGO_FEATURE_NO_STD", "1");
+    let err = Handler::from_tokens(quote::quote! { thread_local }).unwrap_err();
+    assert_eq!(err.to_string(), "`thread_local` can't be used with the `no-std` feature");
+}
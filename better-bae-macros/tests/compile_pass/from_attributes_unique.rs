@@ -0,0 +1,30 @@
+use better_bae::{FromAttributes, TryFromAttributes};
+
+#[derive(Debug, FromAttributes)]
+struct MyAttr {
+    name: syn::Ident,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[other]
+        #[my_attr(name = first)]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes_unique(&item.attrs).unwrap();
+    assert_eq!(attr.name, "first");
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(name = first)]
+        #[my_attr(name = second)]
+        struct Foo;
+    };
+    let err = MyAttr::from_attributes_unique(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "duplicate `#[my_attr]` attribute");
+
+    // The lenient methods keep using the first one
+    assert_eq!(MyAttr::from_attributes(&item.attrs).unwrap().name, "first");
+
+    let err = MyAttr::from_attributes_unique(&[]).unwrap_err();
+    assert_eq!(err.to_string(), "missing attribute `#[my_attr]`");
+}
@@ -0,0 +1,30 @@
+use better_bae::{FromAttributes, TryFromAttributes};
+
+#[derive(Debug, FromAttributes)]
+struct MyAttr {
+    a: syn::LitInt,
+    b: Option<syn::LitInt>,
+    switch: Option<()>,
+}
+
+fn main() {
+    let attr = MyAttr::from_tokens(quote::quote! { a = 1, b = 2, switch }).unwrap();
+    assert_eq!(attr.a.base10_digits(), "1");
+    assert_eq!(attr.b.unwrap().base10_digits(), "2");
+    assert!(attr.switch.is_some());
+
+    let attr = MyAttr::from_tokens(quote::quote! { a = 1 }).unwrap();
+    assert!(attr.b.is_none());
+
+    let err = MyAttr::from_tokens(quote::quote! { b = 2 }).unwrap_err();
+    assert!(err.to_string().ends_with("`#[my_attr]` is missing `a` argument"));
+
+    assert!(MyAttr::from_tokens(quote::quote! { (a = 1) }).is_err());
+
+    let attr = MyAttr::from_attribute_args(quote::quote! { a = 3, switch }).unwrap();
+    assert_eq!(attr.a.base10_digits(), "3");
+    assert!(attr.switch.is_some());
+
+    let attr = MyAttr::from_attribute_args(proc_macro2::TokenStream::new());
+    assert!(attr.is_err());
+}
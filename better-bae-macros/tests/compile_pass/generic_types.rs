@@ -0,0 +1,53 @@
+use better_bae::{FromAttributes, TryFromAttributes};
+
+#[derive(Debug, FromAttributes)]
+struct Field {
+    ty: syn::Type,
+    default: Option<syn::Expr>,
+    name: Option<syn::Ident>,
+    #[bae(greedy)]
+    greedy_ty: Option<syn::Type>,
+}
+
+fn main() {
+    let attr = Field::from_tokens(quote::quote! {
+        ty = HashMap<String, Vec<u8>>,
+        name = config,
+    })
+    .unwrap();
+    assert_eq!(attr.ty, syn::parse_quote!(HashMap<String, Vec<u8>>));
+    assert_eq!(attr.name.unwrap(), "config");
+
+    let attr = Field::from_tokens(quote::quote! {
+        ty = std::collections::BTreeMap<(u8, u16), Option<Vec<std::string::String>>>,
+        default = std::iter::empty::<(u8, u16)>().collect::<BTreeMap<_, _>>(),
+        name = map,
+    })
+    .unwrap();
+    assert_eq!(
+        attr.ty,
+        syn::parse_quote!(std::collections::BTreeMap<(u8, u16), Option<Vec<std::string::String>>>)
+    );
+    assert_eq!(
+        attr.default.unwrap(),
+        syn::parse_quote!(std::iter::empty::<(u8, u16)>().collect::<BTreeMap<_, _>>())
+    );
+    assert_eq!(attr.name.unwrap(), "map");
+
+    let attr = Field::from_tokens(quote::quote! {
+        ty = Result<Vec::<u8>, Box<dyn Error + Send>>,
+        default = convert::<A, B>(x, y),
+    })
+    .unwrap();
+    assert_eq!(attr.ty, syn::parse_quote!(Result<Vec::<u8>, Box<dyn Error + Send>>));
+    assert_eq!(attr.default.unwrap(), syn::parse_quote!(convert::<A, B>(x, y)));
+
+    let attr = Field::from_tokens(quote::quote! {
+        ty = u8,
+        greedy_ty = HashMap<String, Vec<u8>>,
+        name = config,
+    })
+    .unwrap();
+    assert_eq!(attr.greedy_ty, Some(syn::parse_quote!(HashMap<String, Vec<u8>>)));
+    assert_eq!(attr.name.unwrap(), "config");
+}
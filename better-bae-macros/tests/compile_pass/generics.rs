@@ -0,0 +1,35 @@
+use better_bae::{FromAttributes, TryFromAttributes};
+use syn::parse::Parse;
+
+#[derive(Debug, FromAttributes)]
+#[bae(to_tokens, transparent)]
+struct Foo<T>
+where
+    T: Parse + quote::ToTokens,
+{
+    value: T,
+}
+
+#[derive(Debug, FromAttributes)]
+struct BarBaz<T: Parse, const N: usize> {
+    value: Option<T>,
+    content: Option<syn::Ident>,
+}
+
+fn main() {
+    assert_eq!(Foo::<syn::Ident>::attr_name(), "foo");
+    assert_eq!(BarBaz::<syn::Type, 1>::attr_name(), "bar_baz");
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[foo(value = 1)]
+        #[bar_baz(value = u8, content = foo)]
+        struct Item;
+    };
+
+    let foo = Foo::<syn::LitInt>::from_attributes(&item.attrs).unwrap();
+    assert_eq!(foo.into_inner().base10_digits(), "1");
+
+    let bar_baz = BarBaz::<syn::Type, 1>::from_attributes(&item.attrs).unwrap();
+    assert_eq!(bar_baz.value.unwrap(), syn::parse_quote!(u8));
+    assert_eq!(bar_baz.content.unwrap(), "foo");
+}
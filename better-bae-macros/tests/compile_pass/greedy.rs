@@ -0,0 +1,42 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, FromAttributes)]
+struct MyAttr {
+    #[bae(greedy)]
+    tokens: proc_macro2::TokenStream,
+
+    #[bae(greedy)]
+    expr: syn::Expr,
+
+    #[bae(greedy)]
+    ty: Option<syn::Type>,
+
+    switch: Option<()>,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(tokens = a b (c, d), expr = call(1, 2) + 3, switch)]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.tokens.to_string(), "a b (c , d)");
+    assert_eq!(attr.expr, syn::parse_quote!(call(1, 2) + 3));
+    assert!(attr.ty.is_none());
+    assert!(attr.switch.is_some());
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(tokens = x, expr = 1, ty = [u8; 4])]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.ty, Some(syn::parse_quote!([u8; 4])));
+    assert!(attr.switch.is_none());
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(tokens = , expr = 1)]
+        struct Foo;
+    };
+    let err = MyAttr::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "invalid `tokens`: expected a value");
+}
@@ -0,0 +1,33 @@
+use better_bae::{FromAttributes, TryFromAttributes};
+
+#[derive(Debug, FromAttributes)]
+#[bae(to_tokens)]
+struct Table {
+    #[bae(ident_or_str)]
+    name: String,
+    #[bae(ident_or_str)]
+    schema: Option<String>,
+}
+
+fn main() {
+    let attr = Table::from_tokens(quote::quote! { name = users, schema = "public" }).unwrap();
+    assert_eq!(attr.name, "users");
+    assert_eq!(attr.schema.as_deref(), Some("public"));
+
+    let attr = Table::from_tokens(quote::quote! { name = "user accounts" }).unwrap();
+    assert_eq!(attr.name, "user accounts");
+    assert_eq!(attr.schema, None);
+
+    let attr = Table::from_tokens(quote::quote! { name = r#type }).unwrap();
+    assert_eq!(attr.name, "type");
+
+    let tokens = quote::quote!(#attr).to_string();
+    assert_eq!(tokens, quote::quote!((name = "type",)).to_string());
+
+    let err = Table::from_tokens(quote::quote! { name = 1 }).unwrap_err();
+    assert!(
+        err.to_string().contains("expected string literal or identifier"),
+        "{}",
+        err
+    );
+}
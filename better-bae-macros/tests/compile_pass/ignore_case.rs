@@ -0,0 +1,31 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, FromAttributes)]
+struct MyAttr {
+    #[bae(ignore_case)]
+    content_type: Option<syn::LitStr>,
+    name: Option<syn::LitStr>,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(ContentType = "json", name = "x")]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.content_type.unwrap().value(), "json");
+    assert_eq!(attr.name.unwrap().value(), "x");
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(content_type = "json")]
+        struct Foo;
+    };
+    assert!(MyAttr::from_attributes(&item.attrs).unwrap().content_type.is_some());
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(Name = "x")]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert!(attr.name.is_none());
+}
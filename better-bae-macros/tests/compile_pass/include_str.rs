@@ -0,0 +1,22 @@
+use better_bae::{FromAttributes, TryFromAttributes};
+
+#[derive(Debug, FromAttributes)]
+struct Query {
+    #[bae(include_str)]
+    sql: String,
+}
+
+fn main() {
+    // Set by cargo when expanding a proc macro
+    let dir = std::env::temp_dir().join("better_bae_include_str");
+    std::fs::create_dir_all(dir.join("queries")).unwrap();
+    std::fs::write(dir.join("queries/users.sql"), "SELECT * FROM users").unwrap();
+    std::env::set_var("CARGO_MANIFEST_DIR", &dir);
+
+    let attr = Query::from_tokens(quote::quote! { sql = "queries/users.sql" }).unwrap();
+    assert_eq!(attr.sql, "SELECT * FROM users");
+
+    let err = Query::from_tokens(quote::quote! { sql = "queries/missing.sql" }).unwrap_err();
+    assert!(err.to_string().contains("couldn't read `"), "{}", err);
+    assert!(err.to_string().contains("missing.sql"), "{}", err);
+}
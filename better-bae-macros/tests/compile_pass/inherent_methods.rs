@@ -0,0 +1,27 @@
+// `TryFromAttributes` is deliberately not imported
+use better_bae::FromAttributes;
+
+#[derive(Debug, FromAttributes)]
+struct MyAttr {
+    name: syn::Ident,
+}
+
+#[derive(Debug, FromAttributes)]
+struct Generic<T: syn::parse::Parse> {
+    value: T,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(name = foo)]
+        #[generic(value = 1)]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.name, "foo");
+
+    let attr = Generic::<syn::LitInt>::try_from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.unwrap().value.base10_digits(), "1");
+
+    assert!(MyAttr::try_from_attributes(&[]).unwrap().is_none());
+}
@@ -0,0 +1,33 @@
+use better_bae::{FromAttributes, TryFromAttributes};
+
+#[derive(Debug, FromAttributes)]
+struct MyAttr {
+    #[bae(min = 0, max = 1)]
+    ratio: f64,
+
+    #[bae(min = -1)]
+    offset: Option<f32>,
+
+    #[bae(max = 100u8)]
+    percent: Option<u32>,
+}
+
+fn parse(tokens: proc_macro2::TokenStream) -> syn::Result<MyAttr> {
+    MyAttr::from_tokens(tokens)
+}
+
+fn main() {
+    let attr = parse(quote::quote! { ratio = 0.5, offset = -0.5, percent = 100 }).unwrap();
+    assert_eq!(attr.ratio, 0.5);
+    assert_eq!(attr.offset, Some(-0.5));
+    assert_eq!(attr.percent, Some(100));
+
+    let attr = parse(quote::quote! { ratio = 1 }).unwrap();
+    assert_eq!(attr.ratio, 1.0);
+
+    let err = parse(quote::quote! { ratio = 1.5 }).unwrap_err();
+    assert_eq!(err.to_string(), "`ratio` must be between 0 and 1");
+
+    let err = parse(quote::quote! { ratio = 0, offset = -2 }).unwrap_err();
+    assert_eq!(err.to_string(), "`offset` must be at least -1");
+}
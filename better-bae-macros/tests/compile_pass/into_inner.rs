@@ -0,0 +1,37 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, FromAttributes)]
+#[bae(transparent)]
+struct Wrapper {
+    ty: syn::Type,
+}
+
+// Without `transparent` the struct can define its own `into_inner`
+#[derive(Debug, FromAttributes)]
+struct Named {
+    name: syn::Ident,
+}
+
+impl Named {
+    fn into_inner(self) -> String {
+        self.name.to_string()
+    }
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[wrapper(ty = String)]
+        struct Foo;
+    };
+
+    let attr = Wrapper::from_attributes(&item.attrs).unwrap();
+    let ty: syn::Type = attr.into_inner();
+    assert_eq!(ty, syn::parse_quote!(String));
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[named(name = foo)]
+        struct Foo;
+    };
+    let name = Named::from_attributes(&item.attrs).unwrap().into_inner();
+    assert_eq!(name, "foo");
+}
@@ -0,0 +1,23 @@
+use better_bae::{FromAttributes, TryFromAttributes};
+
+#[derive(Debug, FromAttributes)]
+struct MyAttr {
+    name: syn::LitStr,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[derive(Debug)]
+        #[my_attr(not valid arguments)]
+        struct Foo;
+    };
+    assert!(MyAttr::is_present(&item.attrs));
+    assert!(MyAttr::from_attributes(&item.attrs).is_err());
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[derive(Debug)]
+        #[other(name = "x")]
+        struct Foo;
+    };
+    assert!(!MyAttr::is_present(&item.attrs));
+}
@@ -0,0 +1,61 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, FromAttributes)]
+#[bae(to_tokens)]
+struct MyAttr {
+    #[bae(items)]
+    fns: Vec<syn::ItemFn>,
+    #[bae(items)]
+    extra: Vec<syn::Item>,
+    name: Option<syn::Ident>,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(
+            fns = {
+                fn a() {}
+                fn b(x: u8) -> u8 { x + 1 }
+            },
+            extra = { struct Helper; const N: usize = 1; },
+            name = foo,
+        )]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.fns.len(), 2);
+    assert_eq!(attr.fns[0].sig.ident, "a");
+    assert_eq!(attr.fns[1].sig.ident, "b");
+    assert!(matches!(attr.extra[0], syn::Item::Struct(_)));
+    assert!(matches!(attr.extra[1], syn::Item::Const(_)));
+    assert_eq!(attr.name.as_ref().unwrap(), "foo");
+
+    let tokens = quote::quote!(#attr).to_string();
+    let reparsed = syn::parse_str::<MyAttr>(&tokens).unwrap();
+    assert_eq!(reparsed.fns, attr.fns);
+    assert_eq!(reparsed.extra, attr.extra);
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(fns = {})]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert!(attr.fns.is_empty());
+    assert!(attr.extra.is_empty());
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(fns = { fn a() {} struct NotAFn; })]
+        struct Foo;
+    };
+    assert!(MyAttr::from_attributes(&item.attrs)
+        .unwrap_err()
+        .to_string()
+        .starts_with("invalid `fns`: "));
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(fns = [])]
+        struct Foo;
+    };
+    let err = MyAttr::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "invalid `fns`: expected curly braces");
+}
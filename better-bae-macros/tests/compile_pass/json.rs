@@ -0,0 +1,40 @@
+use std::collections::BTreeMap;
+
+use better_bae::FromAttributes;
+
+#[derive(Debug, FromAttributes)]
+struct MyAttr {
+    #[bae(json)]
+    config: BTreeMap<String, u32>,
+    #[bae(json)]
+    ports: Option<Vec<u16>>,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(config = r#"{"a": 1, "b": 2}"#, ports = "[80, 443]")]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.config["a"], 1);
+    assert_eq!(attr.config["b"], 2);
+    assert_eq!(attr.ports, Some(vec![80, 443]));
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(config = r#"{"a": "x"}"#)]
+        struct Foo;
+    };
+    let err = MyAttr::from_attributes(&item.attrs).unwrap_err();
+    assert!(
+        err.to_string().starts_with("invalid `config`: invalid JSON: invalid type"),
+        "{}",
+        err
+    );
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(config = 1)]
+        struct Foo;
+    };
+    let err = MyAttr::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "invalid `config`: expected string literal");
+}
@@ -0,0 +1,51 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, FromAttributes)]
+#[bae(keywords)]
+struct Route {
+    method: syn::LitStr,
+    #[bae(rename = "type")]
+    kind: Option<syn::Ident>,
+    r#async: Option<()>,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[route(method = "GET", type = Json, async)]
+        struct Foo;
+    };
+    let route = Route::from_attributes(&item.attrs).unwrap();
+    assert_eq!(route.method.value(), "GET");
+    assert_eq!(route.kind.unwrap(), "Json");
+    assert!(route.r#async.is_some());
+
+    // Keys may be written as raw identifiers
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[route(r#method = "GET", r#type = Json, r#async)]
+        struct Foo;
+    };
+    let route = Route::from_attributes(&item.attrs).unwrap();
+    assert_eq!(route.method.value(), "GET");
+    assert_eq!(route.kind.unwrap(), "Json");
+    assert!(route.r#async.is_some());
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[route(r#methods = "GET")]
+        struct Foo;
+    };
+    let err = Route::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "expected one of: `method`, `type`, `async`"
+    );
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[route(methods = "GET")]
+        struct Foo;
+    };
+    let err = Route::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "expected one of: `method`, `type`, `async`"
+    );
+}
@@ -0,0 +1,32 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, FromAttributes)]
+#[bae(to_tokens)]
+struct MyAttr {
+    bound: syn::Lifetime,
+    outlives: Vec<syn::Lifetime>,
+    static_bound: Option<syn::Lifetime>,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(bound = 'a, outlives = ['b, 'c], static_bound = 'static)]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.bound, syn::parse_quote!('a));
+    assert_eq!(attr.outlives, [syn::parse_quote!('b), syn::parse_quote!('c)] as [syn::Lifetime; 2]);
+    assert_eq!(attr.static_bound, Some(syn::parse_quote!('static)));
+
+    let tokens = quote::quote!(#attr).to_string();
+    let reparsed = syn::parse_str::<MyAttr>(&tokens).unwrap();
+    assert_eq!(reparsed.bound, attr.bound);
+    assert_eq!(reparsed.outlives, attr.outlives);
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(bound = a)]
+        struct Foo;
+    };
+    let err = MyAttr::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "invalid `bound`: expected lifetime");
+}
@@ -0,0 +1,51 @@
+use better_bae::FromAttributes;
+use syn::{punctuated::Punctuated, Token};
+
+#[derive(Debug, PartialEq, FromAttributes)]
+#[bae(list, to_tokens)]
+struct DeriveFor {
+    paths: Vec<syn::Path>,
+}
+
+#[derive(Debug, FromAttributes)]
+#[bae(list)]
+struct Ports {
+    ports: Punctuated<u16, Token![,]>,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[derive_for(Foo, bar::Bar, Baz,)]
+        struct Foo;
+    };
+    let attr = DeriveFor::from_attributes(&item.attrs).unwrap();
+    let paths: Vec<syn::Path> = vec![
+        syn::parse_quote!(Foo),
+        syn::parse_quote!(bar::Bar),
+        syn::parse_quote!(Baz),
+    ];
+    assert_eq!(attr.paths, paths);
+
+    let tokens = quote::quote!(#attr).to_string();
+    assert_eq!(syn::parse_str::<DeriveFor>(&tokens).unwrap(), attr);
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[derive_for()]
+        struct Foo;
+    };
+    assert!(DeriveFor::from_attributes(&item.attrs).unwrap().paths.is_empty());
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[ports(80, 443)]
+        struct Foo;
+    };
+    let attr = Ports::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.ports.into_iter().collect::<Vec<_>>(), [80, 443]);
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[ports(80 443)]
+        struct Foo;
+    };
+    let err = Ports::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "expected `,`");
+}
@@ -0,0 +1,58 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, PartialEq, FromAttributes)]
+#[bae(to_tokens)]
+struct MyAttr {
+    #[bae(list_separator = ";")]
+    tags: Vec<syn::Ident>,
+    #[bae(list_separator = ";")]
+    types: Vec<syn::Type>,
+    #[bae(meta_list, list_separator = "|")]
+    either: Vec<syn::Ident>,
+    plain: Vec<syn::Ident>,
+    #[bae(list_separator = ";")]
+    values: Vec<syn::Expr>,
+    #[bae(list_separator = ";")]
+    visibilities: Vec<syn::Visibility>,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(
+            tags = [a; b; c;],
+            types = [HashMap<K, V>; (u8, u16)],
+            either(left | right),
+            plain = [x, y],
+            values = [a; f(b, c) + 1; d < e],
+            visibilities = [pub; pub(crate)],
+        )]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.tags, ["a", "b", "c"]);
+    assert_eq!(attr.types.len(), 2);
+    assert_eq!(attr.either, ["left", "right"]);
+    assert_eq!(attr.plain, ["x", "y"]);
+    assert_eq!(
+        attr.values,
+        [
+            syn::parse_quote!(a),
+            syn::parse_quote!(f(b, c) + 1),
+            syn::parse_quote!(d < e),
+        ] as [syn::Expr; 3]
+    );
+    assert_eq!(
+        attr.visibilities,
+        [syn::parse_quote!(pub), syn::parse_quote!(pub(crate))] as [syn::Visibility; 2]
+    );
+
+    let tokens = quote::quote!(#attr).to_string();
+    assert_eq!(syn::parse_str::<MyAttr>(&tokens).unwrap(), attr);
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(tags = [a, b])]
+        struct Foo;
+    };
+    let err = MyAttr::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "invalid `tags`: expected `;`");
+}
@@ -0,0 +1,64 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, PartialEq, FromAttributes)]
+#[bae(to_tokens)]
+struct Route {
+    #[bae(default = "vec![syn::parse_quote!(GET)]")]
+    methods: Vec<syn::Ident>,
+    ports: Vec<u16>,
+    #[bae(default = "syn::parse_quote!(\"/\")")]
+    path: syn::LitStr,
+    #[bae(required)]
+    tags: Vec<syn::LitStr>,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[route(methods = [GET, POST], ports = [80, 443,], path = "/a", tags = ["x"])]
+        struct Foo;
+    };
+    let attr = Route::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.methods, ["GET", "POST"]);
+    assert_eq!(attr.ports, [80, 443]);
+    assert_eq!(attr.path.value(), "/a");
+    assert_eq!(attr.tags.len(), 1);
+
+    let tokens = quote::quote!(#attr).to_string();
+    assert_eq!(
+        syn::parse_str::<Route>(&tokens).unwrap(),
+        attr,
+        "round trip of {}",
+        tokens
+    );
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[route(tags = [])]
+        struct Foo;
+    };
+    let attr = Route::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.methods, ["GET"]);
+    assert!(attr.ports.is_empty());
+    assert_eq!(attr.path.value(), "/");
+    assert!(attr.tags.is_empty());
+
+    // An explicitly empty list overrides the default
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[route(methods = [], tags = [])]
+        struct Foo;
+    };
+    let attr = Route::from_attributes(&item.attrs).unwrap();
+    assert!(attr.methods.is_empty());
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[route(methods = [GET])]
+        struct Foo;
+    };
+    let err = Route::from_attributes(&item.attrs).unwrap_err();
+    assert!(err.to_string().ends_with("`#[route]` is missing `tags` argument"));
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[route(tags = "x")]
+        struct Foo;
+    };
+    assert!(Route::from_attributes(&item.attrs).is_err());
+}
@@ -0,0 +1,34 @@
+use better_bae::{AnyLit, FromAttributes};
+
+#[derive(Debug, FromAttributes)]
+struct MyAttr {
+    string: syn::Lit,
+    int: syn::Lit,
+    boolean: syn::Lit,
+    any: Option<AnyLit>,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(string = "s", int = 3, boolean = true, any = 1.5)]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert!(matches!(attr.string, syn::Lit::Str(lit) if lit.value() == "s"));
+    assert!(matches!(attr.int, syn::Lit::Int(lit) if lit.base10_digits() == "3"));
+    assert!(matches!(attr.boolean, syn::Lit::Bool(lit) if lit.value));
+
+    let any = attr.any.unwrap();
+    assert_eq!(any.as_float::<f64>().unwrap(), 1.5);
+    assert_eq!(any.as_int::<u8>().unwrap_err().to_string(), "expected an integer literal");
+
+    let lit = |tokens: &str| syn::parse_str::<AnyLit>(tokens).unwrap();
+    assert_eq!(lit("\"s\"").as_str().unwrap(), "s");
+    assert_eq!(lit("300").as_int::<u16>().unwrap(), 300);
+    assert!(lit("300").as_int::<u8>().is_err());
+    assert_eq!(lit("2").as_float::<f32>().unwrap(), 2.0);
+    assert!(lit("false").as_bool().is_ok());
+    assert_eq!(lit("'c'").as_char().unwrap(), 'c');
+
+    assert!(syn::parse_str::<MyAttr>("(string = foo, int = 1, boolean = true)").is_err());
+}
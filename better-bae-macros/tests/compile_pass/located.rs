@@ -0,0 +1,34 @@
+use better_bae::{FromAttributes, TryFromAttributes};
+use syn::spanned::Spanned;
+
+#[derive(Debug, FromAttributes)]
+struct Route {
+    path: syn::LitStr,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_str(
+        r#"
+        #[derive(Debug)]
+        #[route(path = "/")]
+        struct Foo;
+        "#,
+    )
+    .unwrap();
+
+    let (route, span) = Route::try_from_attributes_located(&item.attrs)
+        .unwrap()
+        .unwrap();
+    assert_eq!(route.path.value(), "/");
+    assert_eq!(format!("{:?}", span), format!("{:?}", item.attrs[1].span()));
+
+    assert!(Route::try_from_attributes_located(&item.attrs[..1])
+        .unwrap()
+        .is_none());
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[route(path = 1)]
+        struct Foo;
+    };
+    assert!(Route::try_from_attributes_located(&item.attrs).is_err());
+}
@@ -0,0 +1,45 @@
+use better_bae::{AttrMeta, FromAttributes};
+
+#[derive(Debug, FromAttributes)]
+struct MyMarker;
+
+#[derive(Debug, FromAttributes)]
+struct EmptyMarker {}
+
+#[derive(Debug, FromAttributes)]
+struct MetaMarker {
+    #[bae(capture_meta)]
+    meta: AttrMeta,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_marker]
+        #[empty_marker()]
+        #[meta_marker]
+        struct Foo;
+    };
+    MyMarker::from_attributes(&item.attrs).unwrap();
+    EmptyMarker::from_attributes(&item.attrs).unwrap();
+    let marker = MetaMarker::from_attributes(&item.attrs).unwrap();
+    assert!(marker.meta.path.is_ident("meta_marker"));
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_marker()]
+        struct Foo;
+    };
+    MyMarker::from_attributes(&item.attrs).unwrap();
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_marker(foo)]
+        struct Foo;
+    };
+    let err = MyMarker::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "this attribute takes no arguments");
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_marker = "foo"]
+        struct Foo;
+    };
+    assert!(MyMarker::from_attributes(&item.attrs).is_err());
+}
@@ -0,0 +1,47 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, FromAttributes)]
+#[bae(merge)]
+struct Config {
+    name: syn::LitStr,
+    level: Option<u8>,
+    verbose: Option<()>,
+    tags: Vec<syn::Ident>,
+}
+
+#[derive(Debug, FromAttributes)]
+#[bae(list, merge)]
+struct Paths {
+    paths: Vec<syn::Path>,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[config(name = "defaults", level = 1, verbose, tags = [a])]
+        #[paths(a, b)]
+        struct Defaults;
+    };
+    let mut config = Config::from_attributes(&item.attrs).unwrap();
+    let mut paths = Paths::from_attributes(&item.attrs).unwrap();
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[config(name = "overrides", tags = [b, c])]
+        #[paths(c)]
+        struct Overrides;
+    };
+    config.merge(Config::from_attributes(&item.attrs).unwrap());
+    paths.merge(Paths::from_attributes(&item.attrs).unwrap());
+
+    assert_eq!(config.name.value(), "overrides");
+    assert_eq!(config.level, Some(1));
+    assert!(config.verbose.is_some());
+    assert_eq!(config.tags, ["a", "b", "c"]);
+    assert_eq!(paths.paths.len(), 3);
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[config(name = "levels", level = 3)]
+        struct Levels;
+    };
+    config.merge(Config::from_attributes(&item.attrs).unwrap());
+    assert_eq!(config.level, Some(3));
+}
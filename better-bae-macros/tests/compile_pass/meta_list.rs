@@ -0,0 +1,58 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, Default, PartialEq, FromAttributes)]
+#[bae(to_tokens)]
+struct Retry {
+    times: u8,
+    backoff: Option<()>,
+}
+
+#[derive(Debug, PartialEq, FromAttributes)]
+#[bae(to_tokens)]
+struct MyAttr {
+    #[bae(meta_list)]
+    feature: Vec<syn::Ident>,
+    #[bae(meta_list)]
+    retry: Option<Retry>,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(feature(enabled, verbose), retry(times = 3, backoff))]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.feature, ["enabled", "verbose"]);
+    assert_eq!(
+        attr.retry,
+        Some(Retry {
+            times: 3,
+            backoff: Some(())
+        })
+    );
+
+    let tokens = quote::quote!(#attr).to_string();
+    assert_eq!(syn::parse_str::<MyAttr>(&tokens).unwrap(), attr);
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr()]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert!(attr.feature.is_empty());
+    assert!(attr.retry.is_none());
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(retry(backoff))]
+        struct Foo;
+    };
+    let err = MyAttr::from_attributes(&item.attrs).unwrap_err();
+    assert!(err.to_string().ends_with("`#[retry]` is missing `times` argument"));
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(feature = [enabled])]
+        struct Foo;
+    };
+    let err = MyAttr::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "invalid `feature`: expected parentheses");
+}
@@ -0,0 +1,33 @@
+use better_bae::{FromAttributes, TryFromAttributes};
+
+/// Docs on the struct.
+#[derive(Debug, FromAttributes)]
+#[repr(C)]
+#[allow(dead_code)]
+#[bae("mixed")]
+#[doc = "More docs."]
+struct MyAttr {
+    /// Docs on a field.
+    #[allow(unused)]
+    #[cfg(all())]
+    #[bae(greedy)]
+    #[doc(alias = "expression")]
+    #[bae(multiple)]
+    expr: syn::Expr,
+
+    #[cfg_attr(all(), allow(unused))]
+    #[rustfmt::skip]
+    name: Option<syn::Ident>,
+}
+
+fn main() {
+    assert_eq!(MyAttr::attr_name(), "mixed");
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[mixed(expr = 1, expr = 2, name = foo)]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.expr, syn::parse_quote!(2));
+    assert_eq!(attr.name.unwrap(), "foo");
+}
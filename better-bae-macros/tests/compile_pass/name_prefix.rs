@@ -0,0 +1,42 @@
+use better_bae::{FromAttributes, TryFromAttributes};
+
+#[derive(Debug, FromAttributes)]
+#[bae(name_prefix = "route_")]
+struct Route {
+    path: syn::LitStr,
+    #[bae(matched_suffix)]
+    method: String,
+}
+
+fn main() {
+    let item: syn::ItemFn = syn::parse_quote! {
+        #[route(path = "/ignored")]
+        #[route_get(path = "/users")]
+        #[route_post(path = "/users")]
+        fn users() {}
+    };
+    let route = Route::from_attributes(&item.attrs).unwrap();
+    assert_eq!(route.path.value(), "/users");
+    assert_eq!(route.matched_suffix(), "get");
+    assert_eq!(route.method, "get");
+
+    assert!(Route::is_present(&item.attrs));
+    let err = Route::from_attributes_unique(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "duplicate `#[route_*]` attribute");
+
+    let item: syn::ItemFn = syn::parse_quote! {
+        #[crate::route_delete(path = "/users")]
+        fn users() {}
+    };
+    let route = Route::from_attributes(&item.attrs).unwrap();
+    assert_eq!(route.matched_suffix(), "delete");
+
+    let item: syn::ItemFn = syn::parse_quote! {
+        #[route_(path = "/")]
+        #[router(path = "/")]
+        fn users() {}
+    };
+    assert!(Route::try_from_attributes(&item.attrs).unwrap().is_none());
+    let err = Route::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "missing attribute `#[route_*]`");
+}
@@ -0,0 +1,46 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, FromAttributes)]
+struct MyAttr {
+    offset: Option<i32>,
+    #[bae(min = -1.0, max = 1.0)]
+    scale: Option<f64>,
+    lit: Option<syn::LitInt>,
+    float_lit: Option<syn::LitFloat>,
+    unsigned: Option<u8>,
+}
+
+fn parse(tokens: proc_macro2::TokenStream) -> syn::Result<MyAttr> {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(#tokens)]
+        struct Foo;
+    };
+    MyAttr::from_attributes(&item.attrs)
+}
+
+fn main() {
+    let attr = parse(quote::quote! {
+        offset = -5, scale = -0.5, lit = -10, float_lit = -2.5
+    })
+    .unwrap();
+    assert_eq!(attr.offset, Some(-5));
+    assert_eq!(attr.scale, Some(-0.5));
+    assert_eq!(attr.lit.unwrap().base10_parse::<i64>().unwrap(), -10);
+    assert_eq!(attr.float_lit.unwrap().base10_parse::<f32>().unwrap(), -2.5);
+
+    let attr = parse(quote::quote! { offset = 5, scale = -1 }).unwrap();
+    assert_eq!(attr.offset, Some(5));
+    assert_eq!(attr.scale, Some(-1.0));
+
+    let err = parse(quote::quote! { scale = -1.5 }).unwrap_err();
+    assert_eq!(err.to_string(), "`scale` must be between -1.0 and 1.0");
+
+    let err = parse(quote::quote! { unsigned = -1 }).unwrap_err();
+    assert_eq!(err.to_string(), "invalid `unsigned`: invalid digit found in string");
+
+    let err = parse(quote::quote! { offset = - }).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid `offset`: unexpected end of input, expected integer literal"
+    );
+}
@@ -0,0 +1,40 @@
+use better_bae::{FromAttributes, TryFromAttributes};
+use quote::ToTokens;
+
+#[derive(Debug, FromAttributes)]
+#[bae(to_tokens)]
+struct MyAttr {
+    #[bae(nested_tokens)]
+    when: Option<proc_macro2::TokenStream>,
+
+    #[bae(nested_tokens)]
+    expr: syn::Expr,
+
+    name: Option<syn::Ident>,
+}
+
+fn parse(tokens: proc_macro2::TokenStream) -> syn::Result<MyAttr> {
+    MyAttr::from_tokens(tokens)
+}
+
+fn main() {
+    let attr = parse(quote::quote! {
+        when(all(unix, feature = "x")),
+        expr(1 + 2),
+        name = foo,
+    })
+    .unwrap();
+    assert_eq!(attr.when.as_ref().unwrap().to_string(), "all (unix , feature = \"x\")");
+    assert_eq!(attr.expr, syn::parse_quote!(1 + 2));
+    assert_eq!(attr.name.as_ref().unwrap(), "foo");
+    assert_eq!(
+        attr.to_token_stream().to_string(),
+        "(when (all (unix , feature = \"x\")) , expr (1 + 2) , name = foo ,)"
+    );
+
+    let attr = parse(quote::quote! { expr(a) }).unwrap();
+    assert!(attr.when.is_none());
+
+    let err = parse(quote::quote! { expr = a }).unwrap_err();
+    assert_eq!(err.to_string(), "invalid `expr`: expected parentheses");
+}
@@ -0,0 +1,39 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, Default, PartialEq, FromAttributes)]
+#[bae(to_tokens, merge)]
+#[non_exhaustive]
+struct MyAttr {
+    name: Option<syn::LitStr>,
+    switch: Option<()>,
+}
+
+#[derive(Debug, FromAttributes)]
+#[bae(transparent)]
+#[non_exhaustive]
+struct Wrapper {
+    ty: syn::Type,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(name = "x", switch)]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.name.as_ref().unwrap().value(), "x");
+
+    let tokens = quote::quote!(#attr).to_string();
+    assert_eq!(syn::parse_str::<MyAttr>(&tokens).unwrap(), attr);
+
+    let mut merged = MyAttr::default();
+    merged.merge(attr);
+    assert!(merged.switch.is_some());
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[wrapper(ty = String)]
+        struct Foo;
+    };
+    let ty: syn::Type = Wrapper::from_attributes(&item.attrs).unwrap().into_inner();
+    assert_eq!(ty, syn::parse_quote!(String));
+}
@@ -0,0 +1,37 @@
+use better_bae::{FromAttributes, TryFromAttributes};
+use quote::ToTokens;
+
+#[derive(Debug, FromAttributes)]
+#[bae(to_tokens)]
+struct MyAttr {
+    ty: Option<Option<syn::Type>>,
+
+    #[bae(null = "nothing")]
+    count: Option<Option<u8>>,
+}
+
+fn parse(tokens: proc_macro2::TokenStream) -> syn::Result<MyAttr> {
+    MyAttr::from_tokens(tokens)
+}
+
+fn main() {
+    let attr = parse(quote::quote! {}).unwrap();
+    assert_eq!(attr.ty, None);
+    assert_eq!(attr.count, None);
+
+    let attr = parse(quote::quote! { ty = none, count = nothing }).unwrap();
+    assert_eq!(attr.ty, Some(None));
+    assert_eq!(attr.count, Some(None));
+    assert_eq!(attr.to_token_stream().to_string(), "(ty = none , count = nothing ,)");
+
+    let attr = parse(quote::quote! { ty = String, count = 3 }).unwrap();
+    assert_eq!(attr.ty, Some(Some(syn::parse_quote!(String))));
+    assert_eq!(attr.count, Some(Some(3)));
+
+    // Only the bare word is the null keyword
+    let attr = parse(quote::quote! { ty = none::Type }).unwrap();
+    assert_eq!(attr.ty, Some(Some(syn::parse_quote!(none::Type))));
+
+    let err = parse(quote::quote! { count = none }).unwrap_err();
+    assert_eq!(err.to_string(), "invalid `count`: expected integer literal");
+}
@@ -0,0 +1,43 @@
+use better_bae::{FromAttributes, TryFromAttributes};
+
+#[derive(Debug, FromAttributes)]
+struct MyAttr {
+    #[bae(min = 1, max = 65535)]
+    port: u16,
+    ratio: Option<f64>,
+
+    #[bae(min = -10)]
+    offset: Option<i32>,
+
+    #[bae(max = 1.0)]
+    weight: Option<f32>,
+}
+
+fn parse(tokens: proc_macro2::TokenStream) -> syn::Result<MyAttr> {
+    MyAttr::from_tokens(tokens)
+}
+
+fn main() {
+    let attr = parse(quote::quote! { port = 8080, ratio = 0.5 }).unwrap();
+    assert_eq!(attr.port, 8080);
+    assert_eq!(attr.ratio, Some(0.5));
+    assert_eq!(attr.offset, None);
+
+    let attr = parse(quote::quote! { port = 1, ratio = 2, weight = 1.0 }).unwrap();
+    assert_eq!(attr.ratio, Some(2.0));
+    assert_eq!(attr.weight, Some(1.0));
+
+    let err = parse(quote::quote! { port = 0 }).unwrap_err();
+    assert_eq!(err.to_string(), "`port` must be between 1 and 65535");
+
+    let err = parse(quote::quote! { port = 70000 }).unwrap_err();
+    assert_eq!(err.to_string(), "invalid `port`: number too large to fit in target type");
+
+    let err = parse(quote::quote! { port = 1, weight = 1.5 }).unwrap_err();
+    assert_eq!(err.to_string(), "`weight` must be at most 1.0");
+
+    let err = parse(quote::quote! { port = 1, ratio = "a" }).unwrap_err();
+    assert_eq!(err.to_string(), "invalid `ratio`: expected floating point literal or integer literal");
+
+    assert!(parse(quote::quote! { port = foo }).is_err());
+}
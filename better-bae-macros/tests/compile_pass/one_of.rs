@@ -0,0 +1,36 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, FromAttributes)]
+struct Route {
+    #[bae(one_of = "get|post|put")]
+    method: String,
+    #[bae(lowercase, one_of = "json | xml")]
+    format: Option<String>,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[route(method = "post", format = "JSON")]
+        struct Foo;
+    };
+    let route = Route::from_attributes(&item.attrs).unwrap();
+    assert_eq!(route.method, "post");
+    assert_eq!(route.format.as_deref(), Some("json"));
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[route(method = "POST")]
+        struct Foo;
+    };
+    let err = Route::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "`method` must be one of: `get`, `post`, `put`"
+    );
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[route(method = "get", format = "yaml")]
+        struct Foo;
+    };
+    let err = Route::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "`format` must be one of: `json`, `xml`");
+}
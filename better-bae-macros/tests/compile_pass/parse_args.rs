@@ -0,0 +1,33 @@
+use better_bae::{FromAttributes, TryFromAttributes};
+use syn::parse::{Parse, ParseStream};
+
+#[derive(Debug, FromAttributes)]
+struct Options {
+    path: syn::LitStr,
+    strict: Option<()>,
+}
+
+/// Custom syntax which ends with bae arguments, as in `GET, path = "/"`.
+struct Route {
+    method: syn::Ident,
+    options: Options,
+}
+
+impl Parse for Route {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let method = input.parse()?;
+        input.parse::<syn::Token![,]>()?;
+        let options = Options::parse_args(input)?;
+        Ok(Route { method, options })
+    }
+}
+
+fn main() {
+    let route: Route = syn::parse_quote! { GET, path = "/", strict };
+    assert_eq!(route.method, "GET");
+    assert_eq!(route.options.path.value(), "/");
+    assert!(route.options.strict.is_some());
+
+    let err = syn::parse_str::<Route>("GET, strict").err().unwrap();
+    assert!(err.to_string().ends_with("`#[options]` is missing `path` argument"));
+}
@@ -0,0 +1,38 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, PartialEq)]
+struct Name(String);
+
+impl From<syn::Ident> for Name {
+    fn from(ident: syn::Ident) -> Self {
+        Name(ident.to_string())
+    }
+}
+
+#[derive(Debug, FromAttributes)]
+struct MyAttr {
+    #[bae(parse_as = "syn::LitInt")]
+    size: u64,
+    #[bae(parse_as = "syn::Ident")]
+    name: Option<Name>,
+    #[bae(parse_as = "syn::Ident")]
+    aliases: Vec<Name>,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(size = 5u64, name = foo, aliases = [bar, baz])]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.size, 5);
+    assert_eq!(attr.name, Some(Name("foo".to_string())));
+    assert_eq!(attr.aliases, [Name("bar".to_string()), Name("baz".to_string())]);
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(size = "5")]
+        struct Foo;
+    };
+    let err = MyAttr::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "invalid `size`: expected integer literal");
+}
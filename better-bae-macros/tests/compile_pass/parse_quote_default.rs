@@ -0,0 +1,26 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, FromAttributes)]
+struct Field {
+    #[bae(parse_quote_default = "Vec<u8>")]
+    ty: syn::Type,
+    #[bae(parse_quote_default = "std::default::Default::default()")]
+    init: syn::Expr,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[field()]
+        struct Foo;
+    };
+    let attr = Field::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.ty, syn::parse_quote!(Vec<u8>));
+    assert_eq!(attr.init, syn::parse_quote!(std::default::Default::default()));
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[field(ty = String)]
+        struct Foo;
+    };
+    let attr = Field::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.ty, syn::parse_quote!(String));
+}
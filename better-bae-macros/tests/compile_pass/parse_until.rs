@@ -0,0 +1,69 @@
+use better_bae::{FromAttributes, TryFromAttributes};
+use syn::parse::ParseStream;
+
+#[derive(Debug, FromAttributes)]
+struct Options {
+    path: syn::LitStr,
+    strict: Option<()>,
+}
+
+#[derive(Debug, FromAttributes)]
+struct Marker {}
+
+#[derive(Debug, FromAttributes)]
+struct Timeouts {
+    timeout: syn::Expr,
+    retries: Option<syn::Expr>,
+}
+
+fn at_semi(input: ParseStream) -> bool {
+    input.peek(syn::Token![;])
+}
+
+fn parse_route(input: ParseStream) -> syn::Result<(Options, syn::Expr)> {
+    let options = Options::parse_until(input, at_semi)?;
+    input.parse::<syn::Token![;]>()?;
+    Ok((options, input.parse()?))
+}
+
+fn main() {
+    let (options, body) =
+        syn::parse::Parser::parse_str(parse_route, r#"path = "/", strict; handler()"#).unwrap();
+    assert_eq!(options.path.value(), "/");
+    assert!(options.strict.is_some());
+    assert_eq!(body, syn::parse_quote!(handler()));
+
+    // A trailing `,` before the sentinel is allowed
+    let (options, _) =
+        syn::parse::Parser::parse_str(parse_route, r#"path = "/",; handler()"#).unwrap();
+    assert!(options.strict.is_none());
+
+    let err = syn::parse::Parser::parse_str(parse_route, "strict; handler()").unwrap_err();
+    assert!(err.to_string().ends_with("`#[options]` is missing `path` argument"));
+
+    let parse_marker = |input: ParseStream| {
+        let marker = Marker::parse_until(input, at_semi)?;
+        input.parse::<syn::Token![;]>()?;
+        Ok(marker)
+    };
+    assert!(syn::parse::Parser::parse_str(parse_marker, ";").is_ok());
+
+    // Expressions end at the sentinel, not only at a `,`
+    let parse_timeouts = |input: ParseStream| {
+        let timeouts = Timeouts::parse_until(input, at_semi)?;
+        input.parse::<syn::Token![;]>()?;
+        Ok((timeouts, input.parse::<syn::Expr>()?))
+    };
+    let (timeouts, body) =
+        syn::parse::Parser::parse_str(parse_timeouts, "timeout = 5 + 1; handler()").unwrap();
+    assert_eq!(timeouts.timeout, syn::parse_quote!(5 + 1));
+    assert_eq!(body, syn::parse_quote!(handler()));
+
+    let (timeouts, _) = syn::parse::Parser::parse_str(
+        parse_timeouts,
+        "timeout = a < b, retries = f::<A, B>(x); handler()",
+    )
+    .unwrap();
+    assert_eq!(timeouts.timeout, syn::parse_quote!(a < b));
+    assert_eq!(timeouts.retries, Some(syn::parse_quote!(f::<A, B>(x))));
+}
@@ -0,0 +1,43 @@
+use better_bae::{profiling, FromAttributes, ParsedCache};
+
+#[derive(Debug, Clone, FromAttributes)]
+struct MyAttr {
+    name: syn::Ident,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[derive(Debug)]
+        #[my_attr(name = foo)]
+        struct Foo;
+    };
+    let mut cache = ParsedCache::<MyAttr>::new();
+
+    profiling::reset();
+    assert_eq!(cache.from_attributes(&item.attrs).unwrap().name, "foo");
+    assert_eq!(cache.from_attributes(&item.attrs).unwrap().name, "foo");
+    assert_eq!(profiling::stats().parsed, 1);
+
+    let other: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(name = bar)]
+        struct Bar;
+    };
+    assert_eq!(cache.from_attributes(&other.attrs).unwrap().name, "bar");
+    assert_eq!(profiling::stats().parsed, 2);
+
+    let none: syn::ItemStruct = syn::parse_quote!(struct Baz;);
+    assert!(cache.try_from_attributes(&none.attrs).unwrap().is_none());
+    let err = cache.from_attributes(&none.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "missing attribute `#[my_attr]`");
+
+    let invalid: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(name = "x")]
+        struct Foo;
+    };
+    assert!(cache.from_attributes(&invalid.attrs).is_err());
+    assert!(cache.from_attributes(&invalid.attrs).is_err());
+
+    cache.clear();
+    cache.from_attributes(&item.attrs).unwrap();
+    assert_eq!(profiling::stats().parsed, 5);
+}
@@ -0,0 +1,31 @@
+use better_bae::{FromAttributes, TryFromAttributes};
+
+#[derive(Debug, FromAttributes)]
+struct MyAttr {
+    handler: syn::Path,
+    fallback: Option<syn::Path>,
+}
+
+fn parse(tokens: proc_macro2::TokenStream) -> syn::Result<MyAttr> {
+    MyAttr::from_tokens(tokens)
+}
+
+fn main() {
+    let attr = parse(quote::quote! { handler = my_mod::handler, fallback = ::std::mem::drop })
+        .unwrap();
+    assert_eq!(attr.handler, syn::parse_quote!(my_mod::handler));
+    assert_eq!(attr.handler.segments.len(), 2);
+    assert_eq!(attr.fallback.unwrap(), syn::parse_quote!(::std::mem::drop));
+
+    let attr = parse(quote::quote! { handler = foo }).unwrap();
+    assert!(attr.handler.is_ident("foo"));
+
+    let attr = parse(quote::quote! { handler = crate::a::<u8>::b }).unwrap();
+    assert_eq!(attr.handler, syn::parse_quote!(crate::a::<u8>::b));
+
+    let err = parse(quote::quote! { handler = my_mod handler }).unwrap_err();
+    assert_eq!(err.to_string(), "expected `,`");
+
+    let err = parse(quote::quote! { handler = my_mod:: }).unwrap_err();
+    assert_eq!(err.to_string(), "invalid `handler`: unexpected end of input, expected identifier");
+}
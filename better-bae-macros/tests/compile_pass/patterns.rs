@@ -0,0 +1,40 @@
+use better_bae::{FromAttributes, TryFromAttributes};
+
+#[derive(Debug, FromAttributes)]
+struct Arm {
+    pat: syn::Pat,
+    #[bae(single_pat)]
+    single: Option<syn::Pat>,
+    guard: Option<syn::Expr>,
+}
+
+fn main() {
+    let attr = Arm::from_tokens(quote::quote! { pat = Some(x), guard = x > 1 }).unwrap();
+    assert_eq!(attr.pat, syn::parse_quote!(Some(x)));
+    assert_eq!(attr.guard.unwrap(), syn::parse_quote!(x > 1));
+
+    let attr = Arm::from_tokens(quote::quote! { pat = Ok(0) | Err(_), single = (a, b) }).unwrap();
+    match attr.pat {
+        syn::Pat::Or(or) => {
+            assert!(or.leading_vert.is_none());
+            assert_eq!(or.cases.len(), 2);
+        }
+        other => panic!("expected an or-pattern, got {:?}", other),
+    }
+    assert_eq!(attr.single.unwrap(), syn::parse_quote!((a, b)));
+
+    let attr = Arm::from_tokens(quote::quote! { pat = | 1 | 2..=5 }).unwrap();
+    match attr.pat {
+        syn::Pat::Or(or) => {
+            assert!(or.leading_vert.is_some());
+            assert_eq!(or.cases.len(), 2);
+        }
+        other => panic!("expected an or-pattern, got {:?}", other),
+    }
+
+    let attr = Arm::from_tokens(quote::quote! { pat = Point { x, .. } }).unwrap();
+    assert_eq!(attr.pat, syn::parse_quote!(Point { x, .. }));
+
+    let err = Arm::from_tokens(quote::quote! { pat = _, single = A | B }).unwrap_err();
+    assert_eq!(err.to_string(), "expected `,`");
+}
@@ -0,0 +1,59 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, PartialEq, FromAttributes)]
+#[bae(to_tokens, prefix = "db_", suffix = "_opt")]
+struct Database {
+    name: syn::LitStr,
+    pool: Option<syn::LitInt>,
+    #[bae(rename = "url")]
+    connection: Option<syn::LitStr>,
+}
+
+#[derive(Debug, FromAttributes)]
+#[bae(prefix = "tls_")]
+struct Server {
+    #[bae(prefix = "http_")]
+    port: Option<syn::LitInt>,
+    #[bae(prefix = "", suffix = "_file")]
+    cert: Option<syn::LitStr>,
+    key: Option<syn::LitStr>,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[database(db_name_opt = "app", db_pool_opt = 4, url = "x")]
+        struct Foo;
+    };
+    let attr = Database::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.name.value(), "app");
+    assert_eq!(attr.pool.as_ref().unwrap().base10_digits(), "4");
+    assert_eq!(attr.connection.as_ref().unwrap().value(), "x");
+
+    let tokens = quote::quote!(#attr).to_string();
+    assert!(tokens.contains("db_name_opt"), "{}", tokens);
+    assert_eq!(syn::parse_str::<Database>(&tokens).unwrap(), attr);
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[database(name = "app")]
+        struct Foo;
+    };
+    let err = Database::from_attributes(&item.attrs).unwrap_err();
+    assert!(err.to_string().ends_with("`#[database]` is missing `db_name_opt` argument"));
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[server(http_port = 80, cert_file = "a.pem", tls_key = "b.pem")]
+        struct Foo;
+    };
+    let attr = Server::from_attributes(&item.attrs).unwrap();
+    assert!(attr.port.is_some());
+    assert!(attr.cert.is_some());
+    assert!(attr.key.is_some());
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[server(tls_port = 80, tls_cert_file = "a.pem")]
+        struct Foo;
+    };
+    let attr = Server::from_attributes(&item.attrs).unwrap();
+    assert!(attr.port.is_none());
+    assert!(attr.cert.is_none());
+}
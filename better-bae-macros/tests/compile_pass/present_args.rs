@@ -0,0 +1,43 @@
+use better_bae::{FromAttributes, TryFromAttributes};
+
+#[derive(Debug, FromAttributes)]
+#[bae(to_tokens)]
+struct Route {
+    path: syn::LitStr,
+    methods: Vec<syn::Ident>,
+    cached: Option<()>,
+    timeout: Option<u32>,
+    fallback: Option<Option<syn::Path>>,
+    #[bae(rename = "range")]
+    status: Option<(u16, u16)>,
+    #[bae(byte_str)]
+    magic: Vec<u8>,
+}
+
+fn main() {
+    let route = Route::from_tokens(quote::quote! {
+        path = "/",
+        methods = [GET, POST],
+        cached,
+        fallback = none,
+        range = (200, 299),
+        magic = b"ok",
+    })
+    .unwrap();
+    let present = route.present_args().collect::<Vec<_>>();
+    assert_eq!(
+        present,
+        [
+            ("path", Some("\"/\"".to_string())),
+            ("methods", Some("[GET, POST]".to_string())),
+            ("cached", None),
+            ("fallback", Some("none".to_string())),
+            ("range", Some("(200u16 , 299u16 ,)".to_string())),
+            ("magic", Some("b\"ok\"".to_string())),
+        ]
+    );
+
+    let route = Route::from_tokens(quote::quote! { path = "/", timeout = 5 }).unwrap();
+    let keys = route.present_args().map(|(key, _)| key).collect::<Vec<_>>();
+    assert_eq!(keys, ["path", "timeout"]);
+}
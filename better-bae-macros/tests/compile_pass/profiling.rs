@@ -0,0 +1,29 @@
+use better_bae::{profiling, FromAttributes};
+
+#[derive(Debug, FromAttributes)]
+struct MyAttr {
+    name: Option<syn::Ident>,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[derive(Debug)]
+        #[doc = "x"]
+        #[my_attr(name = foo)]
+        #[other]
+        struct Foo;
+    };
+
+    profiling::reset();
+    MyAttr::from_attributes(&item.attrs).unwrap();
+    // Scanning stops at the first match
+    assert_eq!(profiling::stats().scanned, 3);
+    assert_eq!(profiling::stats().parsed, 1);
+
+    MyAttr::try_from_attributes(&item.attrs[..2]).unwrap();
+    let stats = profiling::stats();
+    assert_eq!((stats.scanned, stats.parsed), (5, 1));
+
+    profiling::reset();
+    assert_eq!(profiling::stats(), profiling::ParseStats::default());
+}
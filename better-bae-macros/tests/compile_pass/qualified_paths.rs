@@ -0,0 +1,42 @@
+use better_bae::{FromAttributes, TryFromAttributes};
+
+#[derive(Debug, FromAttributes)]
+struct MyAttr {
+    name: syn::LitStr,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[crate::my_attr(name = "crate")]
+        struct Foo;
+    };
+    assert_eq!(MyAttr::from_attributes(&item.attrs).unwrap().name.value(), "crate");
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[self::my_attr(name = "self")]
+        struct Foo;
+    };
+    assert_eq!(MyAttr::from_attributes(&item.attrs).unwrap().name.value(), "self");
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[super::super::my_attr(name = "super")]
+        struct Foo;
+    };
+    assert_eq!(MyAttr::from_attributes(&item.attrs).unwrap().name.value(), "super");
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[other::my_attr(name = "other")]
+        #[::my_attr(name = "global")]
+        #[my_attr::nested(name = "nested")]
+        struct Foo;
+    };
+    assert!(MyAttr::try_from_attributes(&item.attrs).unwrap().is_none());
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(name = "a")]
+        #[crate::my_attr(name = "b")]
+        struct Foo;
+    };
+    let err = MyAttr::from_attributes_unique(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "duplicate `#[my_attr]` attribute");
+}
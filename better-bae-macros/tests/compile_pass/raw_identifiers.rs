@@ -0,0 +1,33 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, FromAttributes)]
+struct MyAttr {
+    r#type: syn::Type,
+    r#use: Option<()>,
+    r#name: Option<syn::Ident>,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(type = String, use, name = foo)]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.r#type, syn::parse_quote!(String));
+    assert!(attr.r#use.is_some());
+    assert_eq!(attr.r#name.unwrap(), "foo");
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(r#type = u8)]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.r#type, syn::parse_quote!(u8));
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(use)]
+        struct Foo;
+    };
+    let err = MyAttr::from_attributes(&item.attrs).unwrap_err();
+    assert!(err.to_string().ends_with("`#[my_attr]` is missing `type` argument"));
+}
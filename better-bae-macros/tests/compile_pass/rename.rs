@@ -0,0 +1,22 @@
+use better_bae::{FromAttributes, TryFromAttributes};
+use quote::ToTokens;
+
+#[derive(Debug, FromAttributes)]
+#[bae(to_tokens)]
+struct MyAttr {
+    #[bae(rename = "type")]
+    ty: syn::Type,
+
+    #[bae(rename = "name")]
+    r#type: Option<syn::Ident>,
+}
+
+fn main() {
+    let attr = MyAttr::from_tokens(quote::quote! { type = u8, name = foo }).unwrap();
+    assert_eq!(attr.ty, syn::parse_quote!(u8));
+    assert_eq!(attr.r#type.as_ref().unwrap(), "foo");
+    assert_eq!(attr.to_token_stream().to_string(), "(type = u8 , name = foo ,)");
+
+    let err = MyAttr::from_tokens(quote::quote! { name = foo }).unwrap_err();
+    assert!(err.to_string().ends_with("`#[my_attr]` is missing `type` argument"));
+}
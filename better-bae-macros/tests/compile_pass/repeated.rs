@@ -0,0 +1,91 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, PartialEq, FromAttributes)]
+#[bae(to_tokens)]
+struct Column {
+    name: syn::LitStr,
+    ty: syn::Type,
+    primary_key: Option<()>,
+}
+
+#[derive(Debug, PartialEq, FromAttributes)]
+#[bae(to_tokens)]
+struct Table {
+    name: Option<syn::LitStr>,
+    #[bae(repeated)]
+    column: Vec<Column>,
+}
+
+#[derive(Debug, PartialEq, FromAttributes)]
+struct Index {
+    #[bae(repeated, count_range = "1..=2")]
+    column: Vec<Column>,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[table(column(name = "id", ty = i32, primary_key), name = "points", column(name = "x", ty = f64))]
+        struct Foo;
+    };
+    let attr = Table::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.name.as_ref().unwrap().value(), "points");
+    assert_eq!(attr.column.len(), 2);
+    assert_eq!(attr.column[0].name.value(), "id");
+    assert_eq!(attr.column[0].ty, syn::parse_quote!(i32));
+    assert!(attr.column[0].primary_key.is_some());
+    assert_eq!(attr.column[1].name.value(), "x");
+    assert_eq!(attr.column[1].ty, syn::parse_quote!(f64));
+    assert!(attr.column[1].primary_key.is_none());
+
+    let tokens = quote::quote!(#attr).to_string();
+    assert_eq!(syn::parse_str::<Table>(&tokens).unwrap(), attr);
+    assert_eq!(
+        attr.present_args()
+            .into_iter()
+            .map(|(key, _)| key)
+            .collect::<Vec<_>>(),
+        ["name", "column", "column"]
+    );
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[table(name = "empty")]
+        struct Foo;
+    };
+    let attr = Table::from_attributes(&item.attrs).unwrap();
+    assert!(attr.column.is_empty());
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[table(column(name = "id", ty = i32), column(name = "x"))]
+        struct Foo;
+    };
+    let err = Table::from_attributes(&item.attrs).unwrap_err();
+    assert!(err.to_string().ends_with("`#[column]` is missing `ty` argument"));
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[table(column = "id")]
+        struct Foo;
+    };
+    let err = Table::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "invalid `column`: expected parentheses");
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[index(column(name = "x", ty = f64), column(name = "y", ty = f64))]
+        struct Foo;
+    };
+    let attr = Index::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.column.len(), 2);
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[index(column(name = "x", ty = f64), column(name = "y", ty = f64), column(name = "z", ty = f64))]
+        struct Foo;
+    };
+    let err = Index::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "expected 1 to 2 `column` entries, found 3");
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[index()]
+        struct Foo;
+    };
+    let err = Index::from_attributes(&item.attrs).unwrap_err();
+    assert!(err.to_string().ends_with("expected 1 to 2 `column` entries, found 0"));
+}
@@ -0,0 +1,28 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, FromAttributes)]
+struct MyAttr {
+    #[bae(required)]
+    i_know_what_im_doing: Option<()>,
+
+    other: Option<()>,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(i_know_what_im_doing)]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.i_know_what_im_doing, Some(()));
+    assert_eq!(attr.other, None);
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(other)]
+        struct Foo;
+    };
+    let err = MyAttr::from_attributes(&item.attrs).unwrap_err();
+    assert!(err
+        .to_string()
+        .ends_with("`#[my_attr]` is missing `i_know_what_im_doing` argument"));
+}
@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+
+use better_bae::{FromAttributes, IndexMap};
+
+#[derive(Debug, PartialEq, FromAttributes)]
+#[bae(to_tokens)]
+struct Env {
+    name: syn::LitStr,
+    #[bae(rest)]
+    vars: Vec<(syn::Ident, syn::LitStr)>,
+}
+
+#[derive(Debug, FromAttributes)]
+struct Map {
+    #[bae(rest)]
+    values: IndexMap<syn::Ident, u32>,
+}
+
+#[derive(Debug, FromAttributes)]
+#[bae(to_tokens)]
+struct Config {
+    debug: Option<()>,
+    #[bae(rest)]
+    extra: HashMap<String, syn::Lit>,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[env(zeta = "z", name = "app", alpha = "a", r#type = "t")]
+        struct Foo;
+    };
+    let attr = Env::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.name.value(), "app");
+    let keys = attr.vars.iter().map(|(key, _)| key.to_string()).collect::<Vec<_>>();
+    assert_eq!(keys, ["zeta", "alpha", "type"]);
+    assert_eq!(attr.vars[0].1.value(), "z");
+
+    let tokens = quote::quote!(#attr).to_string();
+    assert_eq!(syn::parse_str::<Env>(&tokens).unwrap(), attr);
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[env(name = "app", a = "1", a = "2")]
+        struct Foo;
+    };
+    let err = Env::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "duplicate `a` argument");
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[map(b = 2, a = 1)]
+        struct Foo;
+    };
+    let attr = Map::from_attributes(&item.attrs).unwrap();
+    let entries = attr
+        .values
+        .iter()
+        .map(|(key, value)| (key.to_string(), *value))
+        .collect::<Vec<_>>();
+    assert_eq!(entries, [("b".to_string(), 2), ("a".to_string(), 1)]);
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[map(a = "x")]
+        struct Foo;
+    };
+    let err = Map::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "invalid `a`: expected integer literal");
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[map]
+        struct Foo;
+    };
+    assert!(Map::from_attributes(&item.attrs).is_err());
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[config(level = 3, debug, name = "app")]
+        struct Foo;
+    };
+    let attr = Config::from_attributes(&item.attrs).unwrap();
+    assert!(attr.debug.is_some());
+    assert_eq!(attr.extra.len(), 2);
+    assert_eq!(attr.extra["level"], syn::parse_quote!(3));
+    assert_eq!(attr.extra["name"], syn::parse_quote!("app"));
+
+    let tokens = quote::quote!(#attr).to_string();
+    let reparsed = syn::parse_str::<Config>(&tokens).unwrap();
+    assert_eq!(reparsed.extra, attr.extra);
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[config(verbose)]
+        struct Foo;
+    };
+    let err = Config::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "unknown flag `verbose`, other arguments need a value");
+}
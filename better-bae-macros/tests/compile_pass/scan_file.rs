@@ -0,0 +1,42 @@
+use better_bae::{FromAttributes, TryFromAttributes};
+
+#[derive(Debug, FromAttributes)]
+struct Route {
+    path: syn::LitStr,
+}
+
+fn main() {
+    let file: syn::File = syn::parse_quote! {
+        #[route(path = "/")]
+        fn index() {}
+
+        struct NotARoute;
+
+        mod api {
+            #[route(path = "/api")]
+            fn api() {}
+
+            mod nested {
+                #[route(path = "/api/nested")]
+                struct Nested;
+            }
+        }
+
+        #[route(path = "/after")]
+        fn after() {}
+    };
+
+    let routes = Route::scan_file(&file).unwrap();
+    let paths = routes
+        .iter()
+        .map(|(_, route)| route.path.value())
+        .collect::<Vec<_>>();
+    assert_eq!(paths, ["/", "/api", "/api/nested", "/after"]);
+    assert!(matches!(routes[2].0, syn::Item::Struct(_)));
+
+    let file: syn::File = syn::parse_quote! {
+        #[route]
+        fn broken() {}
+    };
+    assert!(Route::scan_file(&file).is_err());
+}
@@ -0,0 +1,35 @@
+use better_bae::{AttrMeta, FromAttributes};
+
+#[derive(Debug, Default, FromAttributes)]
+#[bae(skip_if_no_args)]
+struct Cache {
+    ttl: u32,
+    shared: Option<()>,
+    #[bae(capture_meta)]
+    meta: AttrMeta,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[cache]
+        struct Foo;
+    };
+    let attr = Cache::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.ttl, 0);
+    assert!(attr.shared.is_none());
+    assert!(attr.meta.path.is_ident("cache"));
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[cache(ttl = 60, shared)]
+        struct Foo;
+    };
+    let attr = Cache::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.ttl, 60);
+    assert!(attr.shared.is_some());
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[cache()]
+        struct Foo;
+    };
+    assert!(Cache::from_attributes(&item.attrs).is_err());
+}
@@ -0,0 +1,44 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, FromAttributes)]
+struct MyAttr {
+    a: Option<syn::LitInt>,
+    b: Option<syn::LitInt>,
+    list: Vec<syn::Ident>,
+}
+
+#[derive(Debug, FromAttributes)]
+#[bae(list)]
+struct Values {
+    values: Vec<syn::Ident>,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(a = 1, b = 2,)]
+        struct Foo;
+    };
+    assert!(MyAttr::from_attributes(&item.attrs).is_ok());
+
+    for item in [
+        quote::quote!(#[my_attr(,)] struct Foo;),
+        quote::quote!(#[my_attr(, a = 1)] struct Foo;),
+        quote::quote!(#[my_attr(a = 1,, b = 2)] struct Foo;),
+        quote::quote!(#[my_attr(a = 1,,)] struct Foo;),
+        quote::quote!(#[values(x,, y)] struct Foo;),
+    ] {
+        let item = syn::parse2::<syn::ItemStruct>(item).unwrap();
+        let err = MyAttr::try_from_attributes(&item.attrs)
+            .err()
+            .or_else(|| Values::try_from_attributes(&item.attrs).err())
+            .unwrap();
+        assert_eq!(err.to_string(), "expected an argument, found an extra `,`");
+    }
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(list = [a,, b])]
+        struct Foo;
+    };
+    let err = MyAttr::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "invalid `list`: expected a value, found an extra `,`");
+}
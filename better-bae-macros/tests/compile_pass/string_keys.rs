@@ -0,0 +1,35 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, PartialEq, FromAttributes)]
+#[bae(string_keys, to_tokens)]
+struct Header {
+    #[bae(rename = "content type")]
+    content_type: syn::LitStr,
+    cache: Option<()>,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[header("content type" = "json", "cache")]
+        struct Foo;
+    };
+    let attr = Header::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.content_type.value(), "json");
+    assert!(attr.cache.is_some());
+
+    let tokens = quote::quote!(#attr).to_string();
+    assert_eq!(syn::parse_str::<Header>(&tokens).unwrap(), attr);
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[header(content_type = "json")]
+        struct Foo;
+    };
+    let err = Header::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "expected string literal");
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[header("other" = 1, "content type" = "json")]
+        struct Foo;
+    };
+    assert!(Header::from_attributes(&item.attrs).is_ok());
+}
@@ -0,0 +1,29 @@
+use better_bae::{FromAttributes, TryFromAttributes};
+
+#[derive(Debug, FromAttributes)]
+struct Column {
+    name: String,
+    #[bae(trim)]
+    comment: Option<String>,
+    #[bae(trim, lowercase)]
+    collation: Option<String>,
+    #[bae(ident_or_str, uppercase)]
+    kind: Option<String>,
+}
+
+fn main() {
+    let attr = Column::from_tokens(quote::quote! {
+        name = " id ",
+        comment = "  primary key ",
+        collation = " C.UTF-8",
+        kind = varchar,
+    })
+    .unwrap();
+    assert_eq!(attr.name, " id ");
+    assert_eq!(attr.comment.as_deref(), Some("primary key"));
+    assert_eq!(attr.collation.as_deref(), Some("c.utf-8"));
+    assert_eq!(attr.kind.as_deref(), Some("VARCHAR"));
+
+    let err = Column::from_tokens(quote::quote! { name = id }).unwrap_err();
+    assert!(err.to_string().contains("expected string literal"), "{}", err);
+}
@@ -0,0 +1,30 @@
+use better_bae::{FromAttributes, TryFromAttributes};
+
+#[derive(Debug, FromAttributes)]
+struct MyAttr {
+    switch: Option<()>,
+    name: Option<syn::Ident>,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(switch = foo, name = bar)]
+        struct Foo;
+    };
+    let err = MyAttr::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "`switch` is a flag and does not take a value");
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(switch, name = bar)]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert!(attr.switch.is_some());
+    assert_eq!(attr.name.unwrap(), "bar");
+
+    let err = MyAttr::from_tokens(quote::quote! { name, switch }).unwrap_err();
+    assert_eq!(err.to_string(), "`name` requires a value: `name = <value>`");
+
+    let err = MyAttr::from_tokens(quote::quote! { switch, name }).unwrap_err();
+    assert_eq!(err.to_string(), "`name` requires a value: `name = <value>`");
+}
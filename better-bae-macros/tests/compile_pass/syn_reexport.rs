@@ -0,0 +1,30 @@
+mod attrs {
+    use better_bae::FromAttributes;
+
+    // The generated code doesn't depend on what `syn` and `proc_macro2` name where it's expanded
+    #[allow(dead_code)]
+    mod syn {}
+    #[allow(dead_code)]
+    mod proc_macro2 {}
+
+    #[derive(Debug, FromAttributes)]
+    #[bae(to_tokens, keywords)]
+    pub struct Route {
+        pub path: ::syn::LitStr,
+        pub r#async: Option<()>,
+    }
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[route(path = "/", async)]
+        struct Foo;
+    };
+    let route = attrs::Route::from_attributes(&item.attrs).unwrap();
+    assert_eq!(route.path.value(), "/");
+    assert!(route.r#async.is_some());
+    assert_eq!(
+        quote::quote!(#route).to_string(),
+        quote::quote!((path = "/", async,)).to_string()
+    );
+}
@@ -0,0 +1,56 @@
+use better_bae::FromAttributes;
+use quote::ToTokens;
+
+#[derive(Debug, Default, PartialEq)]
+struct Count(u32);
+
+impl syn::parse::Parse for Count {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        input.parse::<syn::LitInt>()?.base10_parse().map(Count)
+    }
+}
+
+impl ToTokens for Count {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        self.0.to_tokens(tokens)
+    }
+}
+
+#[derive(Debug, FromAttributes)]
+#[bae(to_tokens)]
+struct MyAttr {
+    name: syn::Ident,
+    r#type: Option<syn::Type>,
+    switch: Option<()>,
+
+    #[bae(skip_if_default)]
+    count: Count,
+
+    #[bae(capture_meta)]
+    meta: better_bae::AttrMeta,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(name = foo, type = Vec<u8>, switch, count = 1)]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(
+        attr.to_token_stream().to_string(),
+        "(name = foo , type = Vec < u8 > , switch , count = 1u32 ,)"
+    );
+
+    let reparsed: MyAttr = syn::parse2(attr.to_token_stream()).unwrap();
+    assert_eq!(reparsed.name, attr.name);
+    assert_eq!(reparsed.r#type, attr.r#type);
+    assert_eq!(reparsed.switch, attr.switch);
+    assert_eq!(reparsed.count, attr.count);
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(name = foo, count = 0)]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.to_token_stream().to_string(), "(name = foo ,)");
+}
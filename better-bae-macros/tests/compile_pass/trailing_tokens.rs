@@ -0,0 +1,21 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, FromAttributes)]
+struct MyAttr {
+    a: Option<u8>,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(a = 1) extra]
+        struct Foo;
+    };
+    let err = MyAttr::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "unexpected tokens after `#[my_attr(...)]`");
+
+    let err = syn::parse_str::<MyAttr>("(a = 1) (a = 2)").unwrap_err();
+    assert_eq!(err.to_string(), "unexpected tokens after `#[my_attr(...)]`");
+
+    let attr = syn::parse_str::<MyAttr>("(a = 1)").unwrap();
+    assert_eq!(attr.a, Some(1));
+}
@@ -0,0 +1,25 @@
+use better_bae::{FromAttributes, TryFromAttributes};
+
+#[derive(Debug, FromAttributes)]
+struct MyAttr {
+    port: Option<u16>,
+    #[bae(transparent_errors)]
+    name: Option<syn::LitStr>,
+}
+
+#[derive(Debug, FromAttributes)]
+#[bae(transparent_errors)]
+struct Transparent {
+    port: Option<u16>,
+}
+
+fn main() {
+    let err = MyAttr::from_tokens(quote::quote! { port = "a" }).unwrap_err();
+    assert_eq!(err.to_string(), "invalid `port`: expected integer literal");
+
+    let err = MyAttr::from_tokens(quote::quote! { name = 1 }).unwrap_err();
+    assert_eq!(err.to_string(), "expected string literal");
+
+    let err = Transparent::from_tokens(quote::quote! { port = "a" }).unwrap_err();
+    assert_eq!(err.to_string(), "expected integer literal");
+}
@@ -0,0 +1,33 @@
+use std::convert::{TryFrom, TryInto};
+
+use better_bae::FromAttributes;
+
+#[derive(Debug, FromAttributes)]
+#[bae(try_from)]
+struct MyAttr {
+    name: syn::Ident,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(name = foo)]
+        #[other(name = bar)]
+        struct Foo;
+    };
+
+    let attr = MyAttr::try_from(&item.attrs[0]).unwrap();
+    assert_eq!(attr.name, "foo");
+
+    let attr: MyAttr = (&item.attrs[0]).try_into().unwrap();
+    assert_eq!(attr.name, "foo");
+
+    let err = MyAttr::try_from(&item.attrs[1]).unwrap_err();
+    assert_eq!(err.to_string(), "expected a `#[my_attr]` attribute");
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(name = "foo")]
+        struct Foo;
+    };
+    let err = MyAttr::try_from(&item.attrs[0]).unwrap_err();
+    assert_eq!(err.to_string(), "invalid `name`: expected identifier");
+}
@@ -0,0 +1,43 @@
+use better_bae::{FromAttributes, TryFromAttributes};
+
+#[derive(Debug, FromAttributes)]
+#[bae(to_tokens)]
+struct Grid {
+    range: (usize, usize),
+    origin: Option<(syn::LitInt, syn::LitInt)>,
+}
+
+#[derive(Debug, FromAttributes)]
+struct Axes {
+    labels: Vec<(syn::Ident, syn::LitStr)>,
+}
+
+fn main() {
+    let attr = Grid::from_tokens(quote::quote! {
+        range = (1, 10),
+        origin = (0, -5,),
+    })
+    .unwrap();
+    assert_eq!(attr.range, (1, 10));
+    let (x, y) = attr.origin.as_ref().unwrap();
+    assert_eq!(x.base10_parse::<i32>().unwrap(), 0);
+    assert_eq!(y.base10_parse::<i32>().unwrap(), -5);
+
+    let attr = Axes::from_tokens(quote::quote! { labels = [(x, "width"), (y, "height")] }).unwrap();
+    assert_eq!(attr.labels[1].0, "y");
+    assert_eq!(attr.labels[1].1.value(), "height");
+
+    let attr = Grid::from_tokens(quote::quote! { range = (1, 10) }).unwrap();
+    assert!(attr.origin.is_none());
+    let tokens = quote::quote!(#attr).to_string();
+    assert_eq!(tokens, quote::quote!((range = (1usize, 10usize,),)).to_string());
+
+    let err = Grid::from_tokens(quote::quote! { range = (1) }).unwrap_err();
+    assert!(err.to_string().ends_with("expected 2 values, found 1"), "{}", err);
+
+    let err = Grid::from_tokens(quote::quote! { range = (1, 2, 3) }).unwrap_err();
+    assert_eq!(err.to_string(), "invalid `range`: expected 2 values, found more");
+
+    let err = Grid::from_tokens(quote::quote! { range = 1 }).unwrap_err();
+    assert!(err.to_string().contains("expected parentheses"), "{}", err);
+}
@@ -0,0 +1,42 @@
+use better_bae::{FromAttributes, TryFromAttributes};
+
+#[derive(Debug, FromAttributes)]
+struct Lenient {
+    name: Option<syn::Ident>,
+}
+
+#[derive(Debug, FromAttributes)]
+#[bae(unknown = "warn")]
+struct Warn {
+    name: Option<syn::Ident>,
+}
+
+#[derive(Debug, FromAttributes)]
+#[bae(unknown = "error")]
+struct Strict {
+    name: Option<syn::Ident>,
+}
+
+#[derive(Debug, FromAttributes)]
+#[bae(deny_unknown_fields)]
+struct DenyUnknown {
+    name: Option<syn::Ident>,
+}
+
+fn main() {
+    let attr = Lenient::from_tokens(quote::quote! { other = (a, b), flag, name = foo }).unwrap();
+    assert_eq!(attr.name.unwrap(), "foo");
+
+    // Unknown arguments are tested in unknown_warn.rs, as `emit_warning!` only works inside a
+    // `#[proc_macro_error]` entry point
+    let attr = Warn::from_tokens(quote::quote! { name = foo }).unwrap();
+    assert_eq!(attr.name.unwrap(), "foo");
+
+    let attr = Strict::from_tokens(quote::quote! { name = foo }).unwrap();
+    assert_eq!(attr.name.unwrap(), "foo");
+    let err = Strict::from_tokens(quote::quote! { name = foo, other = 1 }).unwrap_err();
+    assert_eq!(err.to_string(), "unknown argument `other`");
+
+    let err = DenyUnknown::from_tokens(quote::quote! { other = 1 }).unwrap_err();
+    assert_eq!(err.to_string(), "unknown argument `other`");
+}
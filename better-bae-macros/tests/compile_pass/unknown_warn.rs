@@ -0,0 +1,8 @@
+// Unknown arguments are skipped, with a warning on nightly, and parsing goes on
+#[better_bae_test_macros::lenient(other = (a, b), name = foo, flag)]
+fn handler() {}
+
+fn main() {
+    handler();
+    assert_eq!(NAME, "foo");
+}
@@ -0,0 +1,39 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, FromAttributes)]
+struct MyAttr {
+    vis: syn::Visibility,
+    getter_vis: Option<syn::Visibility>,
+    sig: Option<syn::Signature>,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(vis = pub, getter_vis = pub(crate), sig = fn foo(a: u8, b: u16) -> u8)]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.vis, syn::parse_quote!(pub));
+    assert_eq!(attr.getter_vis, Some(syn::parse_quote!(pub(crate))));
+    let sig = attr.sig.unwrap();
+    assert_eq!(sig.ident, "foo");
+    assert_eq!(sig.inputs.len(), 2);
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        // A `where` clause continues after `,` so it must be the last argument
+        #[my_attr(vis = pub(super), sig = async fn bar<T>(self) where T: Clone, U: Copy)]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.vis, syn::parse_quote!(pub(super)));
+    let sig = attr.sig.unwrap();
+    assert!(sig.asyncness.is_some());
+    assert_eq!(sig.generics.where_clause.unwrap().predicates.len(), 2);
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(vis = , getter_vis = pub)]
+        struct Foo;
+    };
+    let err = MyAttr::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "invalid `vis`: expected a value");
+}
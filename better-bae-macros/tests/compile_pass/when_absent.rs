@@ -0,0 +1,52 @@
+use better_bae::FromAttributes;
+
+#[derive(Debug, FromAttributes)]
+struct MyAttr {
+    #[bae(when_absent = "error")]
+    name: Option<syn::Ident>,
+    #[bae(when_absent = "error")]
+    tags: Vec<syn::Ident>,
+    #[bae(when_absent = "default")]
+    retries: u8,
+    #[bae(when_absent = "default", default = "3")]
+    timeout: u8,
+    #[bae(when_absent = "skip")]
+    comment: Option<String>,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(name = foo, tags = [])]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.name.unwrap(), "foo");
+    assert!(attr.tags.is_empty());
+    assert_eq!(attr.retries, 0);
+    assert_eq!(attr.timeout, 3);
+    assert_eq!(attr.comment, None);
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(name = foo, tags = [a], retries = 2, timeout = 10, comment = "hi")]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.tags, ["a"]);
+    assert_eq!(attr.retries, 2);
+    assert_eq!(attr.timeout, 10);
+    assert_eq!(attr.comment.as_deref(), Some("hi"));
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(tags = [])]
+        struct Foo;
+    };
+    let err = MyAttr::from_attributes(&item.attrs).unwrap_err();
+    assert!(err.to_string().ends_with("`#[my_attr]` is missing `name` argument"));
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(name = foo)]
+        struct Foo;
+    };
+    let err = MyAttr::from_attributes(&item.attrs).unwrap_err();
+    assert!(err.to_string().ends_with("`#[my_attr]` is missing `tags` argument"));
+}
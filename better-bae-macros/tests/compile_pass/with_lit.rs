@@ -0,0 +1,44 @@
+use std::{net::Ipv4Addr, path::PathBuf};
+
+use better_bae::{FromAttributes, WithLit};
+
+#[derive(Debug, PartialEq, FromAttributes)]
+#[bae(to_tokens)]
+struct MyAttr {
+    path: WithLit<PathBuf>,
+    port: Option<WithLit<u16>>,
+    host: Option<WithLit<Ipv4Addr>>,
+}
+
+fn main() {
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(path = "/etc", port = 8080, host = "127.0.0.1")]
+        struct Foo;
+    };
+    let attr = MyAttr::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attr.path.value, PathBuf::from("/etc"));
+    assert!(attr.path.is_absolute());
+    assert!(matches!(&attr.path.lit, syn::Lit::Str(lit) if lit.value() == "/etc"));
+    assert_eq!(attr.port.clone().unwrap().into_inner(), 8080);
+    assert_eq!(attr.host.as_ref().unwrap().value, Ipv4Addr::LOCALHOST);
+
+    let tokens = quote::quote!(#attr).to_string();
+    assert_eq!(syn::parse_str::<MyAttr>(&tokens).unwrap(), attr);
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(path = "/etc", host = "localhost")]
+        struct Foo;
+    };
+    let err = MyAttr::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(err.to_string(), "invalid `host`: invalid IPv4 address syntax");
+
+    let item: syn::ItemStruct = syn::parse_quote! {
+        #[my_attr(path = b"/etc")]
+        struct Foo;
+    };
+    let err = MyAttr::from_attributes(&item.attrs).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid `path`: expected a string, number, character or boolean literal"
+    );
+}
@@ -0,0 +1,47 @@
+//! Proc macros which parse their arguments with better-bae while expanding, so the trybuild
+//! tests can check the diagnostics it reports to the compiler.
+
+use better_bae::{FromAttributes, TryFromAttributes};
+use proc_macro::TokenStream;
+use proc_macro_error::proc_macro_error;
+use quote::quote;
+
+#[derive(FromAttributes)]
+#[bae(emit_diagnostics)]
+#[allow(dead_code)]
+struct Route {
+    path: syn::LitStr,
+    method: Option<syn::Ident>,
+    timeout: Option<u32>,
+}
+
+/// Parses `#[route(...)]` arguments with `#[bae(emit_diagnostics)]` and returns the item as is.
+#[proc_macro_attribute]
+#[proc_macro_error]
+pub fn route(args: TokenStream, item: TokenStream) -> TokenStream {
+    match Route::from_tokens(args.into()) {
+        Ok(_) => item,
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+#[derive(FromAttributes)]
+#[bae(unknown = "warn")]
+struct Lenient {
+    name: syn::Ident,
+}
+
+/// Parses `#[lenient(...)]` arguments with `#[bae(unknown = "warn")]` and defines a `NAME`
+/// constant holding the `name` argument, next to the item.
+#[proc_macro_attribute]
+#[proc_macro_error]
+pub fn lenient(args: TokenStream, item: TokenStream) -> TokenStream {
+    let item = proc_macro2::TokenStream::from(item);
+    match Lenient::from_tokens(args.into()) {
+        Ok(lenient) => {
+            let name = lenient.name.to_string();
+            quote!(const NAME: &str = #name; #item).into()
+        }
+        Err(err) => err.to_compile_error().into(),
+    }
+}
@@ -20,10 +20,43 @@
 //!     //
 //!     // `try_from_attributes` returns `Ok(None)` if the attribute is missing, `Ok(Some(_))` if
 //!     // its there and is valid, `Err(_)` otherwise.
+//!     //
+//!     // They are implemented for `TryFromAttributes` and also as inherent methods, so the trait
+//!     // doesn't need to be in scope. As inherent methods take precedence, a method of the same
+//!     // name defined on the struct conflicts with them.
+//!     //
+//!     // The struct's doc comment is also available as `MyAttr::DOC`, to build help messages.
 //!     FromAttributes,
 //! )]
 //! pub struct MyAttr {
-//!     // Anything that implements `syn::parse::Parse` is supported.
+//!     // Anything that implements `syn::parse::Parse` is supported. That includes `syn::Lit`
+//!     // for values that can be any literal, see also `AnyLit`.
+//!     //
+//!     // `syn::Attribute` is supported as well and parses a single outer attribute, as in
+//!     // `attr = #[derive(Debug)]`.
+//!     //
+//!     // Integer and float primitives, such as `u16` and `f64`, are parsed from number literals.
+//!     // These, and `syn::LitInt` and `syn::LitFloat`, may be negative, as in `offset = -5`.
+//!     //
+//!     // Paths, such as `handler = my_mod::handler`, can be parsed into `syn::Path`. A bare
+//!     // `handler = foo` is a path with a single segment.
+//!     //
+//!     // `syn::Expr` values are everything up to the next top level `,`, as with
+//!     // `#[bae(greedy)]`, so expressions such as `1 << 4` or `f(a, b) + 1` are supported.
+//!     //
+//!     // `syn::Visibility` and `syn::Signature` are supported as well, as in `vis = pub(crate)`
+//!     // and `sig = fn foo(a: u8) -> u8`. An empty visibility, `vis = ,`, is an error rather than
+//!     // `syn::Visibility::Inherited`. As a `where` clause continues after a `,`, a signature with
+//!     // one must be the last argument.
+//!     //
+//!     // `String` fields are parsed from string literals, as in `name = "foo"`.
+//!     //
+//!     // Delimited values are kept whole, so code can be embedded with `syn::Block`, as in
+//!     // `body = { let x = 1; x + 1 }`, and `,` inside the braces doesn't end the argument.
+//!     //
+//!     // Tuples, such as `(usize, usize)`, are parsed from parenthesized values, as in
+//!     // `range = (1, 10)`, and must have as many values as the tuple has elements. Lists of
+//!     // tuples are supported too, but not by `#[bae(to_tokens)]`.
 //!     mandatory_type: syn::Type,
 //!     mandatory_ident: syn::Ident,
 //!
@@ -32,6 +65,14 @@
 //!     optional_missing: Option<syn::Type>,
 //!     optional_given: Option<syn::Type>,
 //!
+//!     // `Vec<T>` fields are given as a list, such as `list = [a, b]`, and are empty if not
+//!     // specified.
+//!     list: Vec<syn::Ident>,
+//!
+//!     // Fields wrapped in two `Option`s distinguish an absent argument (`None`) from one
+//!     // given as `none` (`Some(None)`).
+//!     nullable: Option<Option<syn::Type>>,
+//!
 //!     // A "switch" is something that doesn't take arguments.
 //!     // All fields with type `Option<()>` are considered swiches.
 //!     // They default to `None`.
@@ -45,10 +86,18 @@
 //! //         mandatory_ident = foo,
 //! //         mandatory_type = SomeType,
 //! //         optional_given = OtherType,
+//! //         nullable = none,
+//! //         list = [a, b],
 //! //     )]
 //! //     struct Foo {
 //! //         ...
 //! //     }
+//! //
+//! // The arguments may also be delimited by brackets or braces, as in `#[my_attr[...]]`.
+//! // The path may be qualified with `crate`, `self` or `super`, as in `#[crate::my_attr(...)]`.
+//! //
+//! // A struct without fields is a marker attribute, which is written as `#[my_marker]` or
+//! // `#[my_marker()]` and takes no arguments.
 //!
 //! // the input and output type would normally be `proc_macro::TokenStream` but those
 //! // types cannot be used outside the compiler itself.
@@ -71,6 +120,10 @@
 //!
 //!     assert_eq!(my_attr.mandatory_ident, syn::parse_str::<syn::Ident>("foo").unwrap());
 //!
+//!     assert_eq!(my_attr.nullable, Some(None));
+//!
+//!     assert_eq!(my_attr.list, ["a", "b"]);
+//!
 //!     assert_eq!(my_attr.switch.is_some(), true);
 //!
 //!     // ...
@@ -86,31 +139,1774 @@
 //! #             mandatory_ident = foo,
 //! #             mandatory_type = SomeType,
 //! #             optional_given = OtherType,
+//! #             nullable = none,
+//! #             list = [a, b],
 //! #         )]
 //! #         struct Foo;
 //! #     };
 //! #     my_proc_macro(code);
 //! # }
 //! ```
+//!
+//! # Struct options
+//!
+//! The struct can be customized with `#[bae(...)]`:
+//!
+//! - `#[bae("name")]`: the name of the attribute. Defaults to the struct's name in snake case.
+//! - `#[bae(to_tokens)]`: implement `quote::ToTokens`, which emits the parenthesized arguments,
+//!   such as `(switch, name = foo)`. That is the inverse of the `syn::parse::Parse` impl so
+//!   parsed attributes can be reconstructed. All field types must implement `ToTokens`. Also
+//!   generates `fn present_args(&self) -> impl Iterator<Item = (&'static str, Option<String>)>`,
+//!   the key of each argument given along with its value displayed as tokens, for summaries and
+//!   debug output. Flags have no value, and `rest`, `catch_all_switches` and `flatten` fields
+//!   aren't listed.
+//! - `#[bae(unknown = "...")]`: what to do with arguments that don't match any field. One of:
+//!   - `"ignore"` (the default): skip the argument, up to the next top level `,`.
+//!   - `"warn"`: skip the argument and emit a warning with `proc_macro_error::emit_warning!`.
+//!     The proc macro's entry point must be annotated with `#[proc_macro_error]`. proc-macro-error
+//!     1.0 can only emit warnings on nightly and drops them on stable, where `"warn"` behaves
+//!     exactly like `"ignore"`.
+//!   - `"error"`: fail with an "unknown argument" error.
+//! - `#[bae(deny_unknown_fields)]`: the same as `#[bae(unknown = "error")]`, under serde's name.
+//! - `#[bae(after_parse = "method")]`: call `fn method(&mut self, input: ParseStream) ->
+//!   syn::Result<()>` to parse custom syntax following the arguments. The arguments end at the
+//!   first token that isn't an identifier or when an argument isn't followed by `,`. The hook runs
+//!   after mandatory fields have been checked and must consume the rest of the input.
+//! - `#[bae(examples = "my_attr(path = \"x\", flag)")]`: a usage example appended to parse errors
+//!   as `example: ...`. Can be given more than once.
+//! - `#[bae(skip_if_no_args)]`: `#[my_attr]`, without any arguments, is `Default::default()`
+//!   instead of being parsed. The struct must implement `Default`.
+//! - `#[bae(case_insensitive)]`: match keys ignoring case and `_`, so `MandatoryIdent = foo`
+//!   matches the `mandatory_ident` field. Keys set with `rename` are matched the same way, and
+//!   two keys which only differ in case or `_` are an error.
+//! - `#[bae(prefix = "x_")]` and `#[bae(suffix = "_x")]`: added to the key of every field, so
+//!   with `prefix = "x_"` the `name` field is given as `x_name = ...`. Keys set with `rename` are
+//!   used as is.
+//! - `#[bae(name_prefix = "route_")]`: match every attribute whose name starts with the prefix,
+//!   such as `#[route_get(...)]` and `#[route_post(...)]`, instead of a single name. The first
+//!   matching attribute is parsed. What follows the prefix is stored in the field marked
+//!   `#[bae(matched_suffix)]`, a `String`, and returned by the generated `matched_suffix()`.
+//! - `#[bae(env_prefix = "MY_ATTR_")]`: a missing argument is parsed from the environment
+//!   variable named by the prefix followed by the key in upper snake case, such as
+//!   `MY_ATTR_MAX_AGE` for `max_age`, if it is set. It takes precedence over the field's
+//!   `default`, and is checked against `min`, `max`, `one_of` and `count_range` like an
+//!   argument. Flags aren't read from the environment. Values are parsed as Rust tokens, like
+//!   the value of an argument: a `syn::LitStr` field needs the quotes, as in
+//!   `MY_ATTR_HOST='"localhost"'` in a shell, and rejects a bare `localhost`, while an integer
+//!   field is given as `MY_ATTR_MAX_AGE=60`. The variables are read while the proc macro runs,
+//!   for the crate being compiled, and cargo doesn't rebuild it when they change unless a build
+//!   script prints `cargo:rerun-if-env-changed=...`.
+//! - `#[bae(at_least_one_field)]`: error if none of the fields is given, as in `#[my_attr()]`,
+//!   listing the keys. Useful when all the fields are optional.
+//! - `#[bae(all_optional)]`: a missing argument is `Default::default()` whatever the field's
+//!   type, so the types of the non-`Option` fields must implement `Default`. `Option` fields are
+//!   still `None`, and a field's own `default`, `required` or `when_absent` takes precedence.
+//! - `#[bae(string_keys)]`: keys are given as string literals, as in `"content type" = "json"`,
+//!   instead of identifiers. Use `rename` for keys that aren't valid field names.
+//! - `#[bae(keywords)]`: match the keys as keyword tokens, generated with `syn::custom_keyword!`,
+//!   so an unknown key fails with the expected keys, as in "expected one of: `method`, `path`".
+//!   Keys may also be written as raw identifiers, such as `r#type = ...`. Can't be combined
+//!   with `string_keys`, `case_insensitive`, `after_parse` or `rest`, and unknown keys are
+//!   always an error, so `unknown` can only be `"error"`.
+//! - `#[bae(emit_diagnostics)]`: keep parsing after an invalid argument. The first error is
+//!   returned as usual, once all arguments have been parsed. If there were more, all of them are
+//!   also emitted with `proc_macro_error::emit_error!`, so they are all shown at once, which
+//!   replaces the macro's output, the returned error included. The proc macro's entry point must
+//!   be annotated with `#[proc_macro_error]`.
+//! - `#[bae(allow_missing_parens)]`: allow the attribute without parentheses, as in `#[my_attr]`,
+//!   which is then parsed as if it had no arguments. Otherwise only marker attributes can be
+//!   written without them.
+//! - `#[bae(list)]`: the arguments are values without keys, as in `#[derive_for(Foo, Bar)]`. The
+//!   struct has a single `Vec<T>` or `Punctuated<T, Token![,]>` field holding them.
+//! - `#[bae(transparent)]`: for newtype attributes wrapping a single field, generate
+//!   `fn into_inner(self) -> T` returning it.
+//! - `#[bae(try_from)]`: implement `TryFrom<&syn::Attribute>` with `syn::Error` as the error.
+//!   The attribute's path is checked as by `try_from_attributes`, including any `name_prefix`,
+//!   and an attribute with another path is an error rather than ignored.
+//! - `#[bae(merge)]`: generate `fn merge(&mut self, other: Self)`, which layers `other` over
+//!   `self`, such as overrides over defaults. Per field:
+//!   - `Option` fields, including switches, are replaced when `other`'s is `Some`. For switches
+//!     that means either one being given is enough.
+//!   - `Vec` fields, `#[bae(rest)]` fields and the values of `#[bae(list)]` structs are appended to.
+//!   - Other fields are replaced, including those with a `default`, as whether the argument was
+//!     given isn't known anymore.
+//! - `#[bae(transparent_errors)]`: errors from parsing a value are reported as is, instead of
+//!   being prefixed with ``invalid `key`: ``. Can also be given on individual fields.
+//!
+//! # Field options
+//!
+//! Fields can be customized with `#[bae(...)]`:
+//!
+//! - `#[bae(rename = "key")]`: the key of the argument. Defaults to the field's name. No two
+//!   fields may have the same key. It must be an identifier unless the struct has
+//!   `#[bae(string_keys)]`.
+//! - `#[bae(capture_meta)]`: the field isn't parsed from the arguments but holds the
+//!   [`AttrMeta`] of the attribute it was parsed from.
+//! - `#[bae(greedy)]`: the value is everything up to the next top level `,`, or up to where the
+//!   `stop` of `parse_until` returns `true`, which is then parsed as the field's type. Useful for
+//!   types whose parser would otherwise stop early or consume the separator. With
+//!   `after_parse`, a `syn::Expr` or `syn::Visibility` value that isn't `greedy` ends where its
+//!   own parser stops instead, as anything may follow the arguments.
+//! - `#[bae(required)]`: the argument must be given even though the field is an `Option`. Most
+//!   useful for switches that must be explicitly opted into, the field is then always `Some(())`.
+//! - `#[bae(when_absent = "error")]`: what to do when the argument isn't given, in place of what
+//!   the field's type implies. `"error"` requires it, even for `Option` and `Vec` fields.
+//!   `"default"` uses `default` if given and `Default::default()` otherwise, for non-`Option`
+//!   fields. `"skip"` leaves an `Option` field `None`, as it would be without the option.
+//! - `#[bae(multiple)]`: the argument may be given more than once, the last value is kept.
+//!   Without it repeating an argument is an error.
+//! - `#[bae(skip_if_default)]`: with `#[bae(to_tokens)]`, the argument is omitted when the field
+//!   equals `Default::default()`. Requires the field type to implement `PartialEq` and `Default`.
+//!   Note that a mandatory field omitted this way can't be parsed back.
+//! - `#[bae(min = 1, max = 65535)]`: for integer and float fields, the value must be within the
+//!   bounds. Either bound may be omitted. Float fields accept integer bounds, as in `min = 0`,
+//!   integer fields need integer bounds.
+//! - `#[bae(nested_tokens)]`: the argument is given as `key(...)`, without `=`, and the contents
+//!   of the parentheses are parsed as the field's type. With a `proc_macro2::TokenStream` field
+//!   that captures nested syntax, such as `when(all(unix, feature = "x"))`, for custom parsing
+//!   later.
+//! - `#[bae(list_separator = ";")]`: for `Vec<T>` fields, the punctuation character separating
+//!   the values, as in `tags = [a; b; c]`. Defaults to `,`.
+//! - `#[bae(meta_list)]`: the argument is given as `key(...)`, like in Rust's own attributes.
+//!   `Vec<T>` fields hold the `,` separated items, as in `feature(enabled, verbose)`. Other
+//!   fields are parsed as a nested bae struct, as in `retry(times = 3)`.
+//! - `#[bae(repeated)]`: for `Vec<T>` fields of a bae struct `T`, the argument is given as
+//!   `key(...)` once per element, each parsed as a `T`, as in
+//!   `column(name = "id", ty = i32), column(name = "x", ty = f64)`.
+//! - `#[bae(conflicts_with = "other")]`: the argument can't be given together with the `other`
+//!   field's, the one given second is reported. Can be given more than once.
+//! - `#[bae(forbidden_with = "feature-name")]`: the argument is an error while the cargo feature
+//!   is enabled. As proc macros can't see the features of the crate being compiled, the feature
+//!   counts as enabled when its `CARGO_FEATURE_FEATURE_NAME` environment variable is set. Cargo
+//!   only sets these for build scripts, which can pass them on to the compiler with
+//!   `cargo:rustc-env=CARGO_FEATURE_FEATURE_NAME=1`. May be given more than once.
+//! - `#[bae(rest)]`: collects the arguments that don't match any other field, instead of
+//!   handling them as [unknown](#struct-options). The field is a `Vec<(K, T)>`, an
+//!   `IndexMap<K, T>` with the optional `indexmap` feature, or a `HashMap<K, T>`, where the keys
+//!   `K` are `syn::Ident` or `String` and the values are parsed as `T`, such as `syn::Lit`. The
+//!   first two keep the arguments in the order they were written, so code generated from them is
+//!   deterministic. Unknown flags, arguments without a value, are an error unless there is a
+//!   `catch_all_switches` field.
+//! - `#[bae(catch_all_switches)]`: collects the flags that don't match any other field into a
+//!   `Vec<syn::Ident>`, as in `#[lint(pedantic)]`, instead of handling them as unknown. Arguments
+//!   with a value still go to the `rest` field, if any, so the two can be used together. Can't be
+//!   used with `flatten`.
+//! - `#[bae(count_range = "1..=3")]`: for `Vec` fields, the number of values allowed, as an
+//!   integer range which may be open ended, such as `2..` or `..=5`. A missing argument has no
+//!   values. With `repeated` it is the number of times `key(...)` is given.
+//! - `#[bae(parse_as = "syn::LitStr")]`: the value is parsed as the given type and then
+//!   converted to the field's type with `From`. `syn::LitInt` and `syn::LitFloat` values are
+//!   converted with `base10_parse` instead, so `parse_as = "syn::LitInt"` on an `u64` field
+//!   accepts `5u64`.
+//! - `#[bae(byte_str)]`: for `Vec<u8>` fields, the value is a byte string, as in
+//!   `magic = b"abc"`. Without it `Vec<u8>` fields are lists like other `Vec` fields, as in
+//!   `magic = [1, 2]`.
+//! - `#[bae(flatten)]`: the field is another attribute struct, parsed from the arguments no
+//!   other field matches, so its keys are given inline as in `#[route(path = "/", origin = "*")]`.
+//!   It takes the arguments whose keys are among its own, as reported by `accepts_key`, and
+//!   arguments matching neither struct are unknown to the outer one, handled by its `unknown`
+//!   option. Presence is "any" rather than "all-or-nothing": an `Option` field is `None` only if
+//!   none of its keys are given, and any one of them makes it `Some`, so its required fields must
+//!   then all be given too. Errors from the nested struct name the outer attribute. Only one
+//!   field can be flattened, and not together with `#[bae(rest)]`.
+//! - `#[bae(ident_or_str)]`: for `String` fields, the value is either an identifier or a string
+//!   literal, as in `name = users` or `name = "user accounts"`. `#[bae(to_tokens)]` emits a string
+//!   literal.
+//! - `#[bae(include_str)]`: for `String` fields, the value is the path of a file, relative to
+//!   the `CARGO_MANIFEST_DIR` of the crate being compiled, as in `schema = "schema.sql"`, and the
+//!   field holds its contents. Unlike with `include_str!`, the crate isn't rebuilt when the file
+//!   changes. Can't be used with `#[bae(to_tokens)]`, as the path isn't kept.
+//! - `#[bae(items)]`: for `Vec<T>` fields, the value is a braced block of `T`s one after the
+//!   other, without separators, as in `fns = { fn a() {} fn b() {} }` for a `Vec<syn::Item>`.
+//! - `#[bae(trim)]`, `#[bae(lowercase)]` and `#[bae(uppercase)]`: for `String` fields, transform
+//!   the value. `trim` removes the surrounding whitespace and is applied first, then `lowercase`
+//!   or `uppercase`, which can't be combined.
+//! - `#[bae(one_of = "get|post|put")]`: for `String` fields, the value must be one of the `|`
+//!   separated strings. Compared after `lowercase` or `uppercase`, so
+//!   `#[bae(lowercase, one_of = "get|post")]` also accepts `"GET"`.
+//! - `#[bae(single_pat)]`: for `syn::Pat` fields, the value is a single pattern, without top
+//!   level `|`, as with syn 2's `Pat::parse_single`. By default `syn::Pat` values may have top
+//!   level `|`, as in `pat = Some(1) | None`, like syn 2's `Pat::parse_multi_with_leading_vert`.
+//! - `#[bae(since = "1.2.0")]` and `#[bae(deprecated)]`: recorded in the generated
+//!   `FIELDS: &[FieldInfo]`, which describes each argument, along with its doc comment, to
+//!   document the attribute. They don't change how the argument is parsed.
+//! - `#[bae(json)]`: the value is a string literal holding JSON, as in `config = r#"{"a": 1}"#`,
+//!   deserialized with `serde_json` into any `serde::Deserialize` type. Requires the optional
+//!   `serde-json` feature, without which the struct fails to compile with a message saying to
+//!   enable it, and can't be used with `#[bae(to_tokens)]`.
+//! - `#[bae(null = "nothing")]`: for `Option<Option<T>>` fields, the bare word given for
+//!   `Some(None)`. Defaults to `none`.
+//! - `#[bae(default = "vec![syn::parse_quote!(GET)]")]`: the expression used when the argument
+//!   isn't given, which makes the field optional. For `Vec<T>` fields an explicitly empty list,
+//!   `key = []`, is still empty. Can't be used on `Option` fields.
+//! - `#[bae(prefix = "x_")]` and `#[bae(suffix = "_x")]`: added to the field's key, like the
+//!   struct options of the same name which they override. `prefix = ""` leaves the key without
+//!   the struct's prefix.
+//! - `#[bae(ignore_case)]`: match this field's key ignoring case and `_`, like the struct's
+//!   `case_insensitive` but leaving the other keys exact.
+//! - `#[bae(default_fn = "make_default")]`: like `default`, but calls the function, which takes
+//!   no arguments and returns the field type. It is only called when the argument isn't given.
+//!   Prefer it to `default` for defaults that don't fit in a short expression.
+//! - `#[bae(parse_quote_default = "Vec<u8>")]`: like `default`, but the string is passed to
+//!   `syn::parse_quote!`, which is shorter for `syn` types. For the `syn` types this crate knows
+//!   about, such as `syn::Type`, a string which doesn't parse is an error when deriving.
 
 pub use better_bae_macros::FromAttributes;
 
+/// Re-exported for `#[bae(rest)]` fields, with the `indexmap` feature.
+#[cfg(feature = "indexmap")]
+pub use indexmap::IndexMap;
+
+/// The path and style of the attribute a value was parsed from.
+///
+/// Populated by `try_from_attributes` on fields marked `#[bae(capture_meta)]`:
+///
+/// ```rust
+/// use better_bae::{AttrMeta, FromAttributes, TryFromAttributes};
+///
+/// #[derive(FromAttributes)]
+/// struct MyAttr {
+///     name: syn::Ident,
+///
+///     #[bae(capture_meta)]
+///     meta: AttrMeta,
+/// }
+///
+/// let attrs: Vec<syn::Attribute> = vec![syn::parse_quote!(#[my_attr(name = foo)])];
+/// let my_attr = MyAttr::from_attributes(&attrs).unwrap();
+///
+/// assert!(my_attr.meta.path.is_ident("my_attr"));
+/// assert_eq!(my_attr.meta.style, syn::AttrStyle::Outer);
+/// ```
+///
+/// When parsed through `syn::parse::Parse` directly there is no attribute to capture, so the
+/// field is left as `AttrMeta::default()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttrMeta {
+    pub path: syn::Path,
+    pub style: syn::AttrStyle,
+}
+
+impl Default for AttrMeta {
+    fn default() -> Self {
+        Self {
+            path: syn::Path {
+                leading_colon: None,
+                segments: syn::punctuated::Punctuated::new(),
+            },
+            style: syn::AttrStyle::Outer,
+        }
+    }
+}
+
+/// Any literal, with accessors that convert it to Rust values.
+///
+/// A `syn::Lit` field accepts any literal as well, but `AnyLit` saves matching on the literal
+/// kind when the value is expected to be of one kind:
+///
+/// ```rust
+/// use better_bae::{AnyLit, FromAttributes, TryFromAttributes};
+///
+/// #[derive(FromAttributes)]
+/// struct MyAttr {
+///     port: AnyLit,
+/// }
+///
+/// let attrs: Vec<syn::Attribute> = vec![syn::parse_quote!(#[my_attr(port = 8080)])];
+/// let my_attr = MyAttr::from_attributes(&attrs).unwrap();
+///
+/// assert_eq!(my_attr.port.as_int::<u16>().unwrap(), 8080);
+/// assert!(my_attr.port.as_str().is_err());
+/// ```
+///
+/// The accessors return an error spanning the literal if it is of another kind.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AnyLit(pub syn::Lit);
+
+impl AnyLit {
+    /// The value of a string literal.
+    pub fn as_str(&self) -> syn::Result<String> {
+        match &self.0 {
+            syn::Lit::Str(lit) => Ok(lit.value()),
+            other => Err(syn::Error::new(other.span(), "expected a string literal")),
+        }
+    }
+
+    /// The value of an integer literal.
+    pub fn as_int<N>(&self) -> syn::Result<N>
+    where
+        N: std::str::FromStr,
+        N::Err: std::fmt::Display,
+    {
+        match &self.0 {
+            syn::Lit::Int(lit) => lit.base10_parse(),
+            other => Err(syn::Error::new(other.span(), "expected an integer literal")),
+        }
+    }
+
+    /// The value of a float literal. Integer literals are accepted as well.
+    pub fn as_float<N>(&self) -> syn::Result<N>
+    where
+        N: std::str::FromStr,
+        N::Err: std::fmt::Display,
+    {
+        match &self.0 {
+            syn::Lit::Float(lit) => lit.base10_parse(),
+            syn::Lit::Int(lit) => lit.base10_parse(),
+            other => Err(syn::Error::new(other.span(), "expected a float literal")),
+        }
+    }
+
+    /// The value of a boolean literal.
+    pub fn as_bool(&self) -> syn::Result<bool> {
+        match &self.0 {
+            syn::Lit::Bool(lit) => Ok(lit.value),
+            other => Err(syn::Error::new(other.span(), "expected `true` or `false`")),
+        }
+    }
+
+    /// The value of a character literal.
+    pub fn as_char(&self) -> syn::Result<char> {
+        match &self.0 {
+            syn::Lit::Char(lit) => Ok(lit.value()),
+            other => Err(syn::Error::new(other.span(), "expected a character literal")),
+        }
+    }
+
+    pub fn span(&self) -> proc_macro2::Span {
+        self.0.span()
+    }
+
+    pub fn into_lit(self) -> syn::Lit {
+        self.0
+    }
+}
+
+impl syn::parse::Parse for AnyLit {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        input.parse().map(AnyLit)
+    }
+}
+
+impl quote::ToTokens for AnyLit {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        self.0.to_tokens(tokens)
+    }
+}
+
+/// A value converted from a literal with `FromStr`, along with the literal itself.
+///
+/// Keeping the literal allows blaming it for errors found after parsing, when the value is valid
+/// but doesn't make sense:
+///
+/// ```rust
+/// use std::path::PathBuf;
+///
+/// use better_bae::{FromAttributes, WithLit};
+///
+/// #[derive(FromAttributes)]
+/// struct MyAttr {
+///     path: WithLit<PathBuf>,
+/// }
+///
+/// fn check(my_attr: &MyAttr) -> syn::Result<()> {
+///     if my_attr.path.is_absolute() {
+///         // Blame the literal, `"/etc"`
+///         return Err(syn::Error::new(my_attr.path.span(), "the path must be relative"));
+///     }
+///     Ok(())
+/// }
+///
+/// let attrs: Vec<syn::Attribute> = vec![syn::parse_quote!(#[my_attr(path = "/etc")])];
+/// let my_attr = MyAttr::from_attributes(&attrs).unwrap();
+///
+/// assert_eq!(my_attr.path.value, PathBuf::from("/etc"));
+/// let err = check(&my_attr).unwrap_err();
+/// assert_eq!(err.to_string(), "the path must be relative");
+/// ```
+///
+/// String literals are converted from their contents, other literals, such as `8080`, from
+/// their text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WithLit<T> {
+    pub value: T,
+    pub lit: syn::Lit,
+}
+
+impl<T> WithLit<T> {
+    pub fn span(&self) -> proc_macro2::Span {
+        self.lit.span()
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> std::ops::Deref for WithLit<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> syn::parse::Parse for WithLit<T>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let lit = input.parse::<syn::Lit>()?;
+        let text = match &lit {
+            syn::Lit::Str(lit) => lit.value(),
+            syn::Lit::Char(lit) => lit.value().to_string(),
+            syn::Lit::Bool(lit) => lit.value.to_string(),
+            syn::Lit::Int(lit) => lit.base10_digits().to_string(),
+            syn::Lit::Float(lit) => lit.base10_digits().to_string(),
+            other => {
+                return Err(syn::Error::new(
+                    other.span(),
+                    "expected a string, number, character or boolean literal",
+                ))
+            }
+        };
+        let value = text
+            .parse()
+            .map_err(|err| syn::Error::new(lit.span(), err))?;
+        Ok(Self { value, lit })
+    }
+}
+
+impl<T> quote::ToTokens for WithLit<T> {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        self.lit.to_tokens(tokens)
+    }
+}
+
+/// A description of an argument, listed by the generated `FIELDS` constant to document an
+/// attribute from its struct:
+///
+/// ```rust
+/// use better_bae::FromAttributes;
+///
+/// #[derive(FromAttributes)]
+/// struct Route {
+///     /// The path the route matches.
+///     path: syn::LitStr,
+///
+///     #[bae(since = "1.2.0")]
+///     timeout: Option<u32>,
+///
+///     #[bae(deprecated)]
+///     cors: Option<()>,
+/// }
+///
+/// let timeout = &Route::FIELDS[1];
+/// assert_eq!(timeout.name, "timeout");
+/// assert_eq!(timeout.since, Some("1.2.0"));
+///
+/// assert_eq!(Route::FIELDS[0].doc, "The path the route matches.");
+/// assert!(Route::FIELDS[2].deprecated);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldInfo {
+    /// The key of the argument.
+    pub name: &'static str,
+    /// The field's doc comment.
+    pub doc: &'static str,
+    /// The version the argument was added in, from `#[bae(since = "...")]`.
+    pub since: Option<&'static str>,
+    /// Whether the field has `#[bae(deprecated)]`.
+    pub deprecated: bool,
+}
+
 pub trait TryFromAttributes
 where
     Self: Sized,
 {
-    fn attr_name() -> &'static str;
+    /// The name of the attribute, such as `my_attr` for `#[my_attr(...)]`.
+    const ATTR_NAME: &'static str;
+
+    fn attr_name() -> &'static str {
+        Self::ATTR_NAME
+    }
 
     fn try_from_attributes(attrs: &[syn::Attribute]) -> syn::Result<Option<Self>>;
 
+    /// Parses the bare arguments, such as `a = 1, b = 2`, from a parse stream. This is what the
+    /// `syn::parse::Parse` impl calls once it has parsed the surrounding parentheses, so it can be
+    /// used to parse an attribute nested inside another one.
+    fn parse_args(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        Self::parse_until(input, |_| false)
+    }
+
+    /// Like `parse_args` but also stops before an argument, or after one instead of expecting a
+    /// `,`, when `stop` returns `true`. The rest of the input is left to the caller, which makes
+    /// it possible to parse the arguments in the middle of a larger grammar:
+    ///
+    /// ```rust
+    /// # use better_bae::{FromAttributes, TryFromAttributes};
+    /// #[derive(FromAttributes)]
+    /// struct Options {
+    ///     path: syn::LitStr,
+    /// }
+    ///
+    /// let parser = |input: syn::parse::ParseStream| {
+    ///     let options = Options::parse_until(input, |input| input.peek(syn::Token![;]))?;
+    ///     input.parse::<syn::Token![;]>()?;
+    ///     let body: syn::Expr = input.parse()?;
+    ///     Ok((options, body))
+    /// };
+    ///
+    /// let (options, _body) =
+    ///     syn::parse::Parser::parse_str(parser, r#"path = "/"; handler()"#).unwrap();
+    /// assert_eq!(options.path.value(), "/");
+    /// ```
+    fn parse_until(
+        input: syn::parse::ParseStream,
+        stop: fn(syn::parse::ParseStream) -> bool,
+    ) -> syn::Result<Self>;
+
+    /// Whether `key` is one of the attribute's keys, which decides the arguments a
+    /// `#[bae(flatten)]` field of this type takes. The derived implementation matches the keys of
+    /// its fields, or any key with `#[bae(rest)]` or `#[bae(catch_all_switches)]`. Defaults to
+    /// `true`, taking every argument the outer attribute doesn't match.
+    fn accepts_key(_key: &str) -> bool {
+        true
+    }
+
+    /// Parses the bare arguments, such as `a = 1, b = 2`, without the attribute's name or the
+    /// surrounding parentheses.
+    fn from_tokens(tokens: proc_macro2::TokenStream) -> syn::Result<Self> {
+        syn::parse::Parser::parse2(Self::parse_args, tokens)
+    }
+
+    /// Like `from_tokens`, for the arguments of a `#[proc_macro_attribute]`, which are given
+    /// without the attribute's name or parentheses:
+    ///
+    /// ```rust,ignore
+    /// #[proc_macro_attribute]
+    /// pub fn route(args: TokenStream, item: TokenStream) -> TokenStream {
+    ///     let route = match Route::from_attribute_args(args) {
+    ///         Ok(route) => route,
+    ///         Err(err) => return err.to_compile_error().into(),
+    ///     };
+    ///     // ...
+    /// }
+    /// ```
+    fn from_attribute_args(args: impl Into<proc_macro2::TokenStream>) -> syn::Result<Self> {
+        Self::from_tokens(args.into())
+    }
+
     fn from_attributes(attrs: &[syn::Attribute]) -> syn::Result<Self> {
         if let Some(attr) = Self::try_from_attributes(attrs)? {
             Ok(attr)
         } else {
             Err(syn::Error::new(
                 proc_macro2::Span::call_site(),
-                &format!("missing attribute `#[{}]`", Self::attr_name()),
+                format!("missing attribute `#[{}]`", Self::attr_name()),
             ))
         }
     }
+
+    /// Whether an attribute is this attribute, judging by its path.
+    fn is_attr(attr: &syn::Attribute) -> bool {
+        __private::is_attr_path(&attr.path, Self::attr_name())
+    }
+
+    /// Whether the attribute is given, without parsing its arguments.
+    fn is_present(attrs: &[syn::Attribute]) -> bool {
+        attrs.iter().any(Self::is_attr)
+    }
+
+    /// Like `from_attributes` but errors, at the second occurrence, if the attribute is given more
+    /// than once instead of using the first one.
+    fn from_attributes_unique(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut matching = attrs.iter().filter(|attr| Self::is_attr(attr));
+        if let (Some(_), Some(second)) = (matching.next(), matching.next()) {
+            return Err(syn::Error::new_spanned(
+                second,
+                format!("duplicate `#[{}]` attribute", Self::attr_name()),
+            ));
+        }
+        Self::from_attributes(attrs)
+    }
+
+    /// Like `try_from_attributes`, also returning the span of the attribute that was parsed, such
+    /// as to point a later error at it.
+    fn try_from_attributes_located(
+        attrs: &[syn::Attribute],
+    ) -> syn::Result<Option<(Self, proc_macro2::Span)>> {
+        for attr in attrs.iter().filter(|attr| Self::is_attr(attr)) {
+            if let Some(parsed) = Self::try_from_attributes(std::slice::from_ref(attr))? {
+                return Ok(Some((parsed, syn::spanned::Spanned::span(attr))));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Like `try_from_attributes`, for the attributes of a `syn` node such as a trait method.
+    fn try_from_attrs_of<T: HasAttributes + ?Sized>(node: &T) -> syn::Result<Option<Self>> {
+        Self::try_from_attributes(node.attributes())
+    }
+
+    /// Like `from_attributes`, for the attributes of a `syn` node such as a trait method.
+    fn from_attrs_of<T: HasAttributes + ?Sized>(node: &T) -> syn::Result<Self> {
+        Self::from_attributes(node.attributes())
+    }
+
+    /// Collects every item of a file carrying the attribute, along with the parsed attribute.
+    ///
+    /// Inline modules are scanned recursively, items inside them follow the module itself.
+    fn scan_file(file: &syn::File) -> syn::Result<Vec<(syn::Item, Self)>> {
+        Self::scan_items(&file.items)
+    }
+
+    /// Like `scan_file`, for a list of items such as the contents of a module.
+    fn scan_items(items: &[syn::Item]) -> syn::Result<Vec<(syn::Item, Self)>> {
+        let mut found = Vec::new();
+        for item in items {
+            if let Some(attr) = Self::try_from_attrs_of(item)? {
+                found.push((item.clone(), attr));
+            }
+            if let syn::Item::Mod(syn::ItemMod {
+                content: Some((_, items)),
+                ..
+            }) = item
+            {
+                found.extend(Self::scan_items(items)?);
+            }
+        }
+        Ok(found)
+    }
+}
+
+/// `syn` nodes which have attributes, such as `syn::DeriveInput`, `syn::Field`, `syn::Variant`
+/// and items, for `TryFromAttributes::from_attrs_of`.
+///
+/// ```rust
+/// use better_bae::{FromAttributes, TryFromAttributes};
+///
+/// #[derive(FromAttributes)]
+/// struct Route {
+///     path: syn::LitStr,
+/// }
+///
+/// let item: syn::ItemTrait = syn::parse_quote! {
+///     trait Api {
+///         #[route(path = "/users")]
+///         fn users(&self);
+///     }
+/// };
+/// let route = Route::from_attrs_of(&item.items[0]).unwrap();
+/// assert_eq!(route.path.value(), "/users");
+/// ```
+pub trait HasAttributes {
+    /// The node's attributes. Nodes which can't have attributes, such as verbatim tokens, have
+    /// none.
+    fn attributes(&self) -> &[syn::Attribute];
+}
+
+macro_rules! impl_has_attributes {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl HasAttributes for $ty {
+                fn attributes(&self) -> &[syn::Attribute] {
+                    &self.attrs
+                }
+            }
+        )*
+    };
+}
+
+impl_has_attributes!(
+    syn::DeriveInput,
+    syn::Field,
+    syn::Variant,
+    syn::File,
+    syn::ItemConst,
+    syn::ItemEnum,
+    syn::ItemExternCrate,
+    syn::ItemFn,
+    syn::ItemForeignMod,
+    syn::ItemImpl,
+    syn::ItemMacro,
+    syn::ItemMacro2,
+    syn::ItemMod,
+    syn::ItemStatic,
+    syn::ItemStruct,
+    syn::ItemTrait,
+    syn::ItemTraitAlias,
+    syn::ItemType,
+    syn::ItemUnion,
+    syn::ItemUse,
+    syn::TraitItemConst,
+    syn::TraitItemMethod,
+    syn::TraitItemType,
+    syn::TraitItemMacro,
+    syn::ImplItemConst,
+    syn::ImplItemMethod,
+    syn::ImplItemType,
+    syn::ImplItemMacro,
+    syn::ForeignItemFn,
+    syn::ForeignItemStatic,
+    syn::ForeignItemType,
+    syn::ForeignItemMacro,
+    syn::TypeParam,
+    syn::LifetimeDef,
+    syn::ConstParam,
+);
+
+impl HasAttributes for [syn::Attribute] {
+    fn attributes(&self) -> &[syn::Attribute] {
+        self
+    }
+}
+
+impl HasAttributes for syn::Item {
+    fn attributes(&self) -> &[syn::Attribute] {
+        use syn::Item;
+
+        match self {
+            Item::Const(item) => item.attributes(),
+            Item::Enum(item) => item.attributes(),
+            Item::ExternCrate(item) => item.attributes(),
+            Item::Fn(item) => item.attributes(),
+            Item::ForeignMod(item) => item.attributes(),
+            Item::Impl(item) => item.attributes(),
+            Item::Macro(item) => item.attributes(),
+            Item::Macro2(item) => item.attributes(),
+            Item::Mod(item) => item.attributes(),
+            Item::Static(item) => item.attributes(),
+            Item::Struct(item) => item.attributes(),
+            Item::Trait(item) => item.attributes(),
+            Item::TraitAlias(item) => item.attributes(),
+            Item::Type(item) => item.attributes(),
+            Item::Union(item) => item.attributes(),
+            Item::Use(item) => item.attributes(),
+            _ => &[],
+        }
+    }
+}
+
+impl HasAttributes for syn::TraitItem {
+    fn attributes(&self) -> &[syn::Attribute] {
+        use syn::TraitItem;
+
+        match self {
+            TraitItem::Const(item) => item.attributes(),
+            TraitItem::Method(item) => item.attributes(),
+            TraitItem::Type(item) => item.attributes(),
+            TraitItem::Macro(item) => item.attributes(),
+            _ => &[],
+        }
+    }
+}
+
+impl HasAttributes for syn::ForeignItem {
+    fn attributes(&self) -> &[syn::Attribute] {
+        use syn::ForeignItem;
+
+        match self {
+            ForeignItem::Fn(item) => item.attributes(),
+            ForeignItem::Static(item) => item.attributes(),
+            ForeignItem::Type(item) => item.attributes(),
+            ForeignItem::Macro(item) => item.attributes(),
+            _ => &[],
+        }
+    }
+}
+
+impl HasAttributes for syn::GenericParam {
+    fn attributes(&self) -> &[syn::Attribute] {
+        use syn::GenericParam;
+
+        match self {
+            GenericParam::Type(param) => param.attributes(),
+            GenericParam::Lifetime(param) => param.attributes(),
+            GenericParam::Const(param) => param.attributes(),
+        }
+    }
+}
+
+impl<T: HasAttributes + ?Sized> HasAttributes for &T {
+    fn attributes(&self) -> &[syn::Attribute] {
+        (**self).attributes()
+    }
+}
+
+impl HasAttributes for syn::ImplItem {
+    fn attributes(&self) -> &[syn::Attribute] {
+        use syn::ImplItem;
+
+        match self {
+            ImplItem::Const(item) => item.attributes(),
+            ImplItem::Method(item) => item.attributes(),
+            ImplItem::Type(item) => item.attributes(),
+            ImplItem::Macro(item) => item.attributes(),
+            _ => &[],
+        }
+    }
+}
+
+/// A cache of parsed attributes, for macros which parse the same attributes more than once.
+///
+/// Entries are keyed by the tokens of the matching attributes and their location, so passing the
+/// same attributes again doesn't parse them again. Errors aren't cached, parsing again returns
+/// them again.
+///
+/// ```rust
+/// use better_bae::{FromAttributes, ParsedCache};
+///
+/// #[derive(Clone, FromAttributes)]
+/// struct MyAttr {
+///     name: syn::Ident,
+/// }
+///
+/// let attrs: Vec<syn::Attribute> = vec![syn::parse_quote!(#[my_attr(name = foo)])];
+/// let mut cache = ParsedCache::<MyAttr>::new();
+///
+/// let first = cache.from_attributes(&attrs).unwrap();
+/// let second = cache.from_attributes(&attrs).unwrap();
+/// assert_eq!(first.name, second.name);
+/// ```
+///
+/// The cache isn't shared between threads, it is meant to live for one invocation of the proc
+/// macro, which runs on a single thread. `syn` values aren't `Send` inside proc macros anyway.
+#[derive(Debug)]
+pub struct ParsedCache<T> {
+    entries: std::collections::HashMap<String, Option<T>>,
+}
+
+impl<T> Default for ParsedCache<T> {
+    fn default() -> Self {
+        Self {
+            entries: std::collections::HashMap::new(),
+        }
+    }
+}
+
+impl<T: TryFromAttributes + Clone> ParsedCache<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Like `TryFromAttributes::try_from_attributes`, reusing the value from an earlier call with
+    /// the same attributes.
+    pub fn try_from_attributes(&mut self, attrs: &[syn::Attribute]) -> syn::Result<Option<T>> {
+        use std::fmt::Write;
+
+        // The whole tokens are the key, rather than a hash of them, so different attributes can't
+        // collide. They are written without their spans, so the span of the `#` is added: the same
+        // attribute elsewhere is parsed again so errors point to the right place
+        let mut key = String::new();
+        for attr in attrs.iter().filter(|attr| T::is_attr(attr)) {
+            let _ = writeln!(key, "{:?} {}", attr.pound_token.span, quote::quote!(#attr));
+        }
+
+        if let Some(parsed) = self.entries.get(&key) {
+            return Ok(parsed.clone());
+        }
+        let parsed = T::try_from_attributes(attrs)?;
+        self.entries.insert(key, parsed.clone());
+        Ok(parsed)
+    }
+
+    /// Like `TryFromAttributes::from_attributes`, reusing the value from an earlier call with the
+    /// same attributes.
+    pub fn from_attributes(&mut self, attrs: &[syn::Attribute]) -> syn::Result<T> {
+        self.try_from_attributes(attrs)?.ok_or_else(|| {
+            syn::Error::new(
+                proc_macro2::Span::call_site(),
+                format!("missing attribute `#[{}]`", T::attr_name()),
+            )
+        })
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+/// Counters of the attributes looked at by `try_from_attributes`, with the `profiling` feature.
+///
+/// The counters are per thread, so read them from the proc macro's own thread after it ran:
+///
+/// ```rust
+/// use better_bae::{profiling, FromAttributes, TryFromAttributes};
+///
+/// #[derive(FromAttributes)]
+/// struct MyAttr {
+///     name: syn::Ident,
+/// }
+///
+/// let attrs: Vec<syn::Attribute> = vec![
+///     syn::parse_quote!(#[derive(Debug)]),
+///     syn::parse_quote!(#[my_attr(name = foo)]),
+/// ];
+/// profiling::reset();
+/// MyAttr::from_attributes(&attrs).unwrap();
+///
+/// let stats = profiling::stats();
+/// assert_eq!(stats.scanned, 2);
+/// assert_eq!(stats.parsed, 1);
+/// ```
+#[cfg(feature = "profiling")]
+pub mod profiling {
+    use std::cell::Cell;
+
+    thread_local! {
+        static STATS: Cell<ParseStats> = Cell::new(ParseStats::default());
+    }
+
+    /// The number of attributes looked at and parsed since the last `reset`.
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub struct ParseStats {
+        /// Attributes whose path was checked.
+        pub scanned: usize,
+        /// Attributes which matched and whose arguments were parsed.
+        pub parsed: usize,
+    }
+
+    /// The counters of the current thread.
+    pub fn stats() -> ParseStats {
+        STATS.with(Cell::get)
+    }
+
+    /// Sets the counters of the current thread back to zero.
+    pub fn reset() {
+        STATS.with(|stats| stats.set(ParseStats::default()));
+    }
+
+    pub(crate) fn count(update: impl FnOnce(&mut ParseStats)) {
+        STATS.with(|stats| {
+            let mut current = stats.get();
+            update(&mut current);
+            stats.set(current);
+        });
+    }
+}
+
+/// Parses a `#[bae(json)]` field's value with `__private::parse_json`, or fails to compile if the
+/// `serde-json` feature isn't enabled.
+#[cfg(feature = "serde-json")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bae_parse_json {
+    ($input:expr) => {
+        $crate::__private::parse_json($input)
+    };
+}
+
+/// Parses a `#[bae(json)]` field's value with `__private::parse_json`, or fails to compile if the
+/// `serde-json` feature isn't enabled.
+#[cfg(not(feature = "serde-json"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bae_parse_json {
+    ($input:expr) => {
+        compile_error!("enable the `serde-json` feature of better-bae to use `#[bae(json)]`")
+    };
+}
+
+/// Helpers used by the generated code. Not public API.
+#[doc(hidden)]
+pub mod __private {
+    use proc_macro2::{TokenStream, TokenTree};
+    use syn::{
+        ext::IdentExt,
+        parse::{discouraged::Speculative, Parse, ParseBuffer, ParseStream},
+    };
+
+    pub use proc_macro2;
+    pub use proc_macro_error::emit_warning;
+    pub use quote::ToTokens;
+    pub use syn;
+
+    /// How `parse_arguments` walks the arguments, from the struct's options.
+    pub struct Arguments {
+        pub string_keys: bool,
+        pub case_insensitive: bool,
+        pub after_parse: bool,
+        pub emit_diagnostics: bool,
+        pub unknown: Unknown,
+        /// With `#[bae(keywords)]`, checks that the next token is one of the keys, so an unknown
+        /// key fails with the keys that were expected.
+        pub keywords: Option<fn(ParseStream) -> syn::Result<()>>,
+    }
+
+    /// What to do with arguments that don't match any field, from `#[bae(unknown = "...")]`.
+    pub enum Unknown {
+        Ignore,
+        Warn,
+        Error,
+    }
+
+    /// The key of an argument.
+    pub struct Key {
+        /// The key as given, without `r#`.
+        pub name: String,
+        /// The key matched against the fields' keys, folded with `case_insensitive`.
+        pub matched: String,
+        pub span: proc_macro2::Span,
+    }
+
+    /// The loop shared by the generated `parse_until` implementations, so each struct only
+    /// generates the parsing of its own fields.
+    ///
+    /// Parses `key` or `key = value` arguments separated by `,` until `input` is empty or `stop`
+    /// returns `true`. `parse` is called after each key to parse the rest of the argument, and
+    /// returns `false` if the key doesn't match any field. With `after_parse` the arguments also
+    /// end at the first token that isn't a key or when an argument isn't followed by `,`.
+    pub fn parse_arguments(
+        input: ParseStream,
+        stop: fn(ParseStream) -> bool,
+        arguments: &Arguments,
+        parse: &mut dyn FnMut(ParseStream, &Key) -> syn::Result<bool>,
+    ) -> syn::Result<()> {
+        let mut errors = Vec::new();
+
+        while !input.is_empty() && !stop(input) {
+            if arguments.after_parse {
+                let is_key = if arguments.string_keys {
+                    input.peek(syn::LitStr)
+                } else {
+                    input.peek(syn::Ident::peek_any)
+                };
+                if !is_key {
+                    break;
+                }
+            }
+
+            check_empty_argument(input)?;
+            let is_keyword = match arguments.keywords {
+                Some(keywords) => keywords(input),
+                None => Ok(()),
+            };
+
+            let result = match is_keyword {
+                Ok(()) => {
+                    let key = parse_key(input, arguments)?;
+                    parse(input, &key).and_then(|matched| {
+                        if matched {
+                            return Ok(());
+                        }
+                        match arguments.unknown {
+                            Unknown::Ignore => skip_argument(input),
+                            Unknown::Warn => {
+                                emit_warning!(key.span, "unknown argument `{}`", key.name);
+                                skip_argument(input)
+                            }
+                            Unknown::Error => Err(syn::Error::new(
+                                key.span,
+                                format!("unknown argument `{}`", key.name),
+                            )),
+                        }
+                    })
+                }
+                Err(err) => Err(err),
+            };
+            // With `emit_diagnostics` an invalid argument is skipped, the errors are reported once
+            // all arguments have been parsed
+            match result {
+                Err(err) if arguments.emit_diagnostics => {
+                    errors.push(err);
+                    skip_argument(input)?;
+                }
+                result => result?,
+            }
+
+            if arguments.after_parse {
+                if input.peek(syn::Token![,]) {
+                    input.parse::<syn::Token![,]>()?;
+                } else {
+                    break;
+                }
+            } else if !input.is_empty() && !stop(input) {
+                input.parse::<syn::Token![,]>()?;
+            }
+        }
+
+        emit_errors(errors)
+    }
+
+    fn parse_key(input: ParseStream, arguments: &Arguments) -> syn::Result<Key> {
+        let (name, span) = if arguments.string_keys {
+            let key = input.parse::<syn::LitStr>()?;
+            (key.value(), key.span())
+        } else {
+            let key = input.call(syn::Ident::parse_any)?;
+            (key.unraw().to_string(), key.span())
+        };
+        let matched = if arguments.case_insensitive {
+            fold_key(&name)
+        } else {
+            name.clone()
+        };
+        Ok(Key {
+            name,
+            matched,
+            span,
+        })
+    }
+
+    /// Errors if a field which can't be repeated was already given.
+    pub fn check_duplicate(given: bool, key: &str, span: proc_macro2::Span) -> syn::Result<()> {
+        if given {
+            return Err(syn::Error::new(
+                span,
+                format!("duplicate `{}` argument", key),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Errors if the cargo feature is enabled, judging by its `CARGO_FEATURE_...` environment
+    /// variable, for `#[bae(forbidden_with = "...")]` fields.
+    pub fn check_forbidden_with(
+        feature: &str,
+        key: &str,
+        span: proc_macro2::Span,
+    ) -> syn::Result<()> {
+        let var = format!("CARGO_FEATURE_{}", feature.to_uppercase().replace('-', "_"));
+        if std::env::var_os(var).is_some() {
+            return Err(syn::Error::new(
+                span,
+                format!("`{}` can't be used with the `{}` feature", key, feature),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Parses the value of the environment variable `name` with `parse`, if it is set, for
+    /// `#[bae(env_prefix = "...")]` structs.
+    pub fn parse_env<T>(
+        name: &str,
+        parse: impl FnOnce(ParseStream) -> syn::Result<T>,
+    ) -> syn::Result<Option<T>> {
+        let value = match std::env::var(name) {
+            Ok(value) => value,
+            Err(_) => return Ok(None),
+        };
+        syn::parse::Parser::parse_str(parse, &value)
+            .map(Some)
+            .map_err(|err| {
+                syn::Error::new(
+                    proc_macro2::Span::call_site(),
+                    format!("invalid `{}` environment variable: {}", name, err),
+                )
+            })
+    }
+
+    /// Parses the `=` after a key, erroring at the key if it was given without a value.
+    pub fn parse_eq(input: ParseStream, key: &str, span: proc_macro2::Span) -> syn::Result<()> {
+        if input.is_empty() || input.peek(syn::Token![,]) {
+            return Err(syn::Error::new(
+                span,
+                format!("`{}` requires a value: `{} = <value>`", key, key),
+            ));
+        }
+        input.parse::<syn::Token![=]>()?;
+        Ok(())
+    }
+
+    /// Returns the first of the errors of a `#[bae(emit_diagnostics)]` struct. If there are more,
+    /// all of them are emitted with `proc_macro_error`, the first included, as once anything is
+    /// emitted it replaces the proc macro's output, along with the returned error.
+    fn emit_errors(errors: Vec<syn::Error>) -> syn::Result<()> {
+        let mut errors = errors.into_iter();
+        let first = match errors.next() {
+            Some(first) => first,
+            None => return Ok(()),
+        };
+        let rest = errors.collect::<Vec<_>>();
+        if !rest.is_empty() {
+            for err in std::iter::once(&first).chain(&rest).flat_map(Clone::clone) {
+                proc_macro_error::emit_error!(err.span(), "{}", err);
+            }
+        }
+        Err(first)
+    }
+
+    pub use crate::__bae_parse_json as parse_json;
+
+    /// Deserializes the contents of a string literal as JSON, for `#[bae(json)]` fields.
+    #[cfg(feature = "serde-json")]
+    pub fn parse_json<T: serde::de::DeserializeOwned>(input: ParseStream) -> syn::Result<T> {
+        let lit = input.parse::<syn::LitStr>()?;
+        serde_json::from_str(&lit.value())
+            .map_err(|err| syn::Error::new(lit.span(), format!("invalid JSON: {}", err)))
+    }
+
+    /// Parses an identifier or a string literal as a `String`, for `#[bae(ident_or_str)]` fields.
+    pub fn parse_ident_or_str(input: ParseStream) -> syn::Result<String> {
+        let lookahead = input.lookahead1();
+        if lookahead.peek(syn::LitStr) {
+            Ok(input.parse::<syn::LitStr>()?.value())
+        } else if lookahead.peek(syn::Ident) {
+            Ok(input.parse::<syn::Ident>()?.unraw().to_string())
+        } else {
+            Err(lookahead.error())
+        }
+    }
+
+    /// Parses a string literal holding a file path, relative to `CARGO_MANIFEST_DIR`, and reads
+    /// the file, for `#[bae(include_str)]` fields.
+    pub fn parse_include_str(input: ParseStream) -> syn::Result<String> {
+        let lit = input.parse::<syn::LitStr>()?;
+        let path = match std::env::var_os("CARGO_MANIFEST_DIR") {
+            Some(dir) => std::path::Path::new(&dir).join(lit.value()),
+            None => std::path::PathBuf::from(lit.value()),
+        };
+        std::fs::read_to_string(&path).map_err(|err| {
+            syn::Error::new(
+                lit.span(),
+                format!("couldn't read `{}`: {}", path.display(), err),
+            )
+        })
+    }
+
+    /// Parses everything up to the next top level `separator`, or up to where `stop` returns true,
+    /// as a `T`.
+    ///
+    /// Delimited groups are single token trees so separators nested inside them never end the
+    /// value. Generic arguments aren't delimited groups, so if the tokens up to the separator don't
+    /// parse, a value which `T` parses by itself up to a later separator, such as `f::<A, B>(x)`,
+    /// is taken instead.
+    pub fn parse_greedy<T: Parse>(
+        input: ParseStream,
+        separator: char,
+        stop: fn(ParseStream) -> bool,
+    ) -> syn::Result<T> {
+        let at_end = |input: ParseStream| {
+            input.is_empty()
+                || stop(input)
+                || matches!(input.cursor().punct(), Some((punct, _)) if punct.as_char() == separator)
+        };
+
+        let fork = input.fork();
+        let mut tokens = TokenStream::new();
+        while !at_end(&fork) {
+            tokens.extend(std::iter::once(fork.parse::<TokenTree>()?));
+        }
+
+        if tokens.is_empty() {
+            return Err(input.error("expected a value"));
+        }
+
+        match syn::parse2(tokens) {
+            Ok(value) => {
+                input.advance_to(&fork);
+                Ok(value)
+            }
+            Err(err) => {
+                let fork = input.fork();
+                match fork.parse::<T>() {
+                    Ok(value) if at_end(&fork) => {
+                        input.advance_to(&fork);
+                        Ok(value)
+                    }
+                    _ => Err(err),
+                }
+            }
+        }
+    }
+
+    /// Parses the attribute's arguments delimited by parentheses, brackets or braces.
+    pub fn parse_delimited<'a>(input: &ParseBuffer<'a>) -> syn::Result<ParseBuffer<'a>> {
+        let content;
+        if input.peek(syn::token::Bracket) {
+            syn::bracketed!(content in input);
+        } else if input.peek(syn::token::Brace) {
+            syn::braced!(content in input);
+        } else {
+            syn::parenthesized!(content in input);
+        }
+        Ok(content)
+    }
+
+    /// Runs `parse` prefixing its errors with ``invalid `key`: ``.
+    pub fn with_key_context<T>(
+        input: ParseStream,
+        key: &str,
+        parse: impl FnOnce(ParseStream) -> syn::Result<T>,
+    ) -> syn::Result<T> {
+        parse(input).map_err(|err| {
+            err.into_iter()
+                .map(|err| syn::Error::new(err.span(), format!("invalid `{}`: {}", key, err)))
+                .reduce(|mut combined, err| {
+                    combined.combine(err);
+                    combined
+                })
+                .expect("syn::Error always holds at least one message")
+        })
+    }
+
+    /// Appends `example: ...` lines to each of the error's messages, keeping their spans.
+    pub fn with_examples(err: syn::Error, examples: &[&str]) -> syn::Error {
+        let mut examples_text = String::new();
+        for example in examples {
+            examples_text.push_str("\nexample: ");
+            examples_text.push_str(example);
+        }
+
+        err.into_iter()
+            .map(|err| syn::Error::new(err.span(), format!("{}{}", err, examples_text)))
+            .reduce(|mut combined, err| {
+                combined.combine(err);
+                combined
+            })
+            .expect("syn::Error always holds at least one message")
+    }
+
+    /// Counts an attribute looked at by `try_from_attributes`, with the `profiling` feature.
+    #[inline(always)]
+    pub fn count_scanned() {
+        #[cfg(feature = "profiling")]
+        crate::profiling::count(|stats| stats.scanned += 1);
+    }
+
+    /// Counts an attribute parsed by `try_from_attributes`, with the `profiling` feature.
+    #[inline(always)]
+    pub fn count_parsed() {
+        #[cfg(feature = "profiling")]
+        crate::profiling::count(|stats| stats.parsed += 1);
+    }
+
+    /// Whether an attribute's path names the attribute `name`, as in `#[name]`. Leading `crate`,
+    /// `self` and `super` segments are ignored, so `#[crate::name]` matches too.
+    pub fn is_attr_path(path: &syn::Path, name: &str) -> bool {
+        attr_path_name(path).is_some_and(|ident| ident == name)
+    }
+
+    /// For `#[bae(name_prefix = "...")]`, what follows `prefix` in an attribute's name, if it
+    /// starts with `prefix` and isn't just `prefix`.
+    pub fn attr_path_suffix(path: &syn::Path, prefix: &str) -> Option<String> {
+        let name = attr_path_name(path)?.to_string();
+        name.strip_prefix(prefix)
+            .filter(|suffix| !suffix.is_empty())
+            .map(str::to_string)
+    }
+
+    /// The name of an attribute, the last segment of its path, unless the path can't name an
+    /// attribute of the macro.
+    fn attr_path_name(path: &syn::Path) -> Option<&syn::Ident> {
+        let mut segments = path.segments.iter().rev();
+        let last = segments.next()?;
+        let qualified = segments.all(|segment| {
+            let qualifier = segment.ident.to_string();
+            segment.arguments.is_empty() && matches!(&*qualifier, "crate" | "self" | "super")
+        });
+        if last.arguments.is_empty() && path.leading_colon.is_none() && qualified {
+            Some(&last.ident)
+        } else {
+            None
+        }
+    }
+
+    /// Folds a key for `case_insensitive` matching by lowercasing it and removing `_`.
+    pub fn fold_key(key: &str) -> String {
+        key.chars()
+            .filter(|c| *c != '_')
+            .flat_map(char::to_lowercase)
+            .collect()
+    }
+
+    /// Parses a bracketed list of values, such as `[a, b]`, allowing a trailing separator.
+    pub fn parse_list<T>(
+        input: ParseStream,
+        separator: char,
+        parse: impl Fn(ParseStream) -> syn::Result<T>,
+    ) -> syn::Result<Vec<T>> {
+        let content;
+        syn::bracketed!(content in input);
+        parse_separated(&content, separator, parse)
+    }
+
+    /// Parses a parenthesized list of values, such as `(a, b)`, allowing a trailing separator.
+    pub fn parse_meta_list<T>(
+        input: ParseStream,
+        separator: char,
+        parse: impl Fn(ParseStream) -> syn::Result<T>,
+    ) -> syn::Result<Vec<T>> {
+        let content;
+        syn::parenthesized!(content in input);
+        parse_separated(&content, separator, parse)
+    }
+
+    fn parse_separated<T>(
+        content: ParseStream,
+        separator: char,
+        parse: impl Fn(ParseStream) -> syn::Result<T>,
+    ) -> syn::Result<Vec<T>> {
+        let mut values = Vec::new();
+        while !content.is_empty() {
+            if let Some((punct, _)) = content.cursor().punct() {
+                if punct.as_char() == separator {
+                    return Err(syn::Error::new(
+                        punct.span(),
+                        format!("expected a value, found an extra `{}`", separator),
+                    ));
+                }
+            }
+            values.push(parse(content)?);
+            if !content.is_empty() {
+                content.step(|cursor| match cursor.punct() {
+                    Some((punct, rest)) if punct.as_char() == separator => Ok(((), rest)),
+                    _ => Err(cursor.error(format!("expected `{}`", separator))),
+                })?;
+            }
+        }
+        Ok(values)
+    }
+
+    fn push_separated<T: ToTokens>(values: &[T], separator: char) -> TokenStream {
+        let mut list = TokenStream::new();
+        for value in values {
+            value.to_tokens(&mut list);
+            proc_macro2::Punct::new(separator, proc_macro2::Spacing::Alone).to_tokens(&mut list);
+        }
+        list
+    }
+
+    /// Appends `key,` to an argument list.
+    pub fn push_switch(args: &mut TokenStream, key: &dyn ToTokens) {
+        key.to_tokens(args);
+        <syn::Token![,]>::default().to_tokens(args);
+    }
+
+    /// Appends `key = value,` to an argument list.
+    pub fn push_arg(args: &mut TokenStream, key: &dyn ToTokens, value: &dyn ToTokens) {
+        key.to_tokens(args);
+        <syn::Token![=]>::default().to_tokens(args);
+        value.to_tokens(args);
+        <syn::Token![,]>::default().to_tokens(args);
+    }
+
+    /// Appends `key = [a, b,],` to an argument list.
+    pub fn push_list<T: ToTokens>(
+        args: &mut TokenStream,
+        key: &dyn ToTokens,
+        values: &[T],
+        separator: char,
+    ) {
+        let list = push_separated(values, separator);
+        let group = proc_macro2::Group::new(proc_macro2::Delimiter::Bracket, list);
+        push_arg(args, key, &TokenTree::Group(group));
+    }
+
+    /// Appends `key(a, b,),` to an argument list.
+    pub fn push_meta_list<T: ToTokens>(
+        args: &mut TokenStream,
+        key: &dyn ToTokens,
+        values: &[T],
+        separator: char,
+    ) {
+        push_nested(args, key, &push_separated(values, separator));
+    }
+
+    /// Appends `key value,` to an argument list, for values whose tokens already are a group.
+    pub fn push_group(args: &mut TokenStream, key: &dyn ToTokens, value: &dyn ToTokens) {
+        key.to_tokens(args);
+        value.to_tokens(args);
+        <syn::Token![,]>::default().to_tokens(args);
+    }
+
+    /// Appends `key(value),` to an argument list.
+    pub fn push_nested(args: &mut TokenStream, key: &dyn ToTokens, value: &dyn ToTokens) {
+        key.to_tokens(args);
+        let group = proc_macro2::Group::new(
+            proc_macro2::Delimiter::Parenthesis,
+            value.to_token_stream(),
+        );
+        args.extend(std::iter::once(TokenTree::Group(group)));
+        <syn::Token![,]>::default().to_tokens(args);
+    }
+
+    /// Appends the arguments of a `#[bae(flatten)]` field, emitted by its `ToTokens` impl in
+    /// parentheses, to an argument list.
+    pub fn push_flattened(args: &mut TokenStream, value: &dyn ToTokens) {
+        for token in value.to_token_stream() {
+            match token {
+                TokenTree::Group(group)
+                    if group.delimiter() == proc_macro2::Delimiter::Parenthesis =>
+                {
+                    args.extend(group.stream());
+                }
+                token => args.extend(std::iter::once(token)),
+            }
+        }
+    }
+
+    /// Parses an integer literal as a `N`, allowing a leading `-`.
+    pub fn parse_int<N>(input: ParseStream) -> syn::Result<N>
+    where
+        N: std::str::FromStr,
+        N::Err: std::fmt::Display,
+    {
+        parse_lit_int(input)?.base10_parse()
+    }
+
+    /// Parses a float or integer literal as a `N`, allowing a leading `-`.
+    pub fn parse_float<N>(input: ParseStream) -> syn::Result<N>
+    where
+        N: std::str::FromStr,
+        N::Err: std::fmt::Display,
+    {
+        let negative = parse_minus(input)?;
+        let lookahead = input.lookahead1();
+        let (digits, span) = if lookahead.peek(syn::LitFloat) {
+            let lit = input.parse::<syn::LitFloat>()?;
+            (lit.base10_digits().to_string(), lit.span())
+        } else if lookahead.peek(syn::LitInt) {
+            let lit = input.parse::<syn::LitInt>()?;
+            (lit.base10_digits().to_string(), lit.span())
+        } else {
+            return Err(lookahead.error());
+        };
+
+        let digits = if negative { format!("-{}", digits) } else { digits };
+        digits
+            .parse()
+            .map_err(|err| syn::Error::new(span, err))
+    }
+
+    /// Parses an integer literal, allowing a leading `-` which is made part of the literal.
+    pub fn parse_lit_int(input: ParseStream) -> syn::Result<syn::LitInt> {
+        let negative = parse_minus(input)?;
+        let lit = input.parse::<syn::LitInt>()?;
+        if negative {
+            Ok(syn::LitInt::new(&format!("-{}", lit), lit.span()))
+        } else {
+            Ok(lit)
+        }
+    }
+
+    /// Parses a float literal, allowing a leading `-` which is made part of the literal.
+    pub fn parse_lit_float(input: ParseStream) -> syn::Result<syn::LitFloat> {
+        let negative = parse_minus(input)?;
+        let lit = input.parse::<syn::LitFloat>()?;
+        if negative {
+            Ok(syn::LitFloat::new(&format!("-{}", lit), lit.span()))
+        } else {
+            Ok(lit)
+        }
+    }
+
+    fn parse_minus(input: ParseStream) -> syn::Result<bool> {
+        if input.peek(syn::Token![-]) {
+            input.parse::<syn::Token![-]>()?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Parses `null` as `None`, anything else with `parse`.
+    pub fn parse_nullable<T>(
+        input: ParseStream,
+        null: &str,
+        parse: impl FnOnce(ParseStream) -> syn::Result<T>,
+    ) -> syn::Result<Option<T>> {
+        let fork = input.fork();
+        if let Ok(ident) = fork.call(syn::Ident::parse_any) {
+            if ident == null && (fork.is_empty() || fork.peek(syn::Token![,])) {
+                input.call(syn::Ident::parse_any)?;
+                return Ok(None);
+            }
+        }
+
+        parse(input).map(Some)
+    }
+
+    /// Parses the `,` before the value at `index` of a tuple of `len` values, or the optional
+    /// trailing `,` once `index` is `len`, erroring if the tuple has fewer or more values.
+    pub fn tuple_value(input: ParseStream, index: usize, len: usize) -> syn::Result<()> {
+        if index > 0 && !input.is_empty() {
+            input.parse::<syn::Token![,]>()?;
+        }
+        if index < len && input.is_empty() {
+            Err(input.error(format!("expected {} values, found {}", len, index)))
+        } else if index == len && !input.is_empty() {
+            Err(input.error(format!("expected {} values, found more", len)))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Displays a list as `[a, b]`, for `present_args`.
+    pub fn display_list<T: ToTokens>(values: &[T]) -> String {
+        let values = values
+            .iter()
+            .map(|value| value.to_token_stream().to_string())
+            .collect::<Vec<_>>();
+        format!("[{}]", values.join(", "))
+    }
+
+    /// Emits values as a parenthesized tuple, for tuple fields.
+    pub struct Tuple<'a>(pub &'a [&'a dyn ToTokens]);
+
+    impl ToTokens for Tuple<'_> {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            let mut values = TokenStream::new();
+            for value in self.0 {
+                value.to_tokens(&mut values);
+                <syn::Token![,]>::default().to_tokens(&mut values);
+            }
+            let group = proc_macro2::Group::new(proc_macro2::Delimiter::Parenthesis, values);
+            tokens.extend(std::iter::once(TokenTree::Group(group)));
+        }
+    }
+
+    /// Emits values one after the other in braces, for `#[bae(items)]` fields.
+    pub struct Items<'a, T>(pub &'a [T]);
+
+    impl<T: ToTokens> ToTokens for Items<'_, T> {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            let mut values = TokenStream::new();
+            for value in self.0 {
+                value.to_tokens(&mut values);
+            }
+            let group = proc_macro2::Group::new(proc_macro2::Delimiter::Brace, values);
+            tokens.extend(std::iter::once(TokenTree::Group(group)));
+        }
+    }
+
+    /// Parses a braced block of `T`s with no separators, for `#[bae(items)]` fields.
+    pub fn parse_items<T: Parse>(input: ParseStream) -> syn::Result<Vec<T>> {
+        let content;
+        syn::braced!(content in input);
+        let mut items = Vec::new();
+        while !content.is_empty() {
+            items.push(content.parse()?);
+        }
+        Ok(items)
+    }
+
+    /// Parses the contents of a parenthesized group as a `T`.
+    pub fn parse_parenthesized<T: Parse>(input: ParseStream) -> syn::Result<T> {
+        let content;
+        syn::parenthesized!(content in input);
+        content.parse()
+    }
+
+    /// Parses the contents of a parenthesized group with `parse`, which must consume all of them.
+    pub fn parse_parenthesized_with<T>(
+        input: ParseStream,
+        parse: impl FnOnce(ParseStream) -> syn::Result<T>,
+    ) -> syn::Result<T> {
+        let content;
+        syn::parenthesized!(content in input);
+        let value = parse(&content)?;
+        if !content.is_empty() {
+            return Err(content.error("unexpected token"));
+        }
+        Ok(value)
+    }
+
+    /// The tokens parsed from `start`, a fork of `end` taken earlier, up to `end`.
+    ///
+    /// Used with `syn::Error::new_spanned` so errors about a value cover all its tokens.
+    pub fn tokens_between(start: ParseStream, end: ParseStream) -> TokenStream {
+        let mut tokens = TokenStream::new();
+        while start.cursor() != end.cursor() {
+            match start.parse::<TokenTree>() {
+                Ok(token) => tokens.extend(std::iter::once(token)),
+                Err(_) => break,
+            }
+        }
+        tokens
+    }
+
+    /// Errors on an empty argument, such as in `a = 1,, b = 2` or `(,)`.
+    pub fn check_empty_argument(input: ParseStream) -> syn::Result<()> {
+        if input.peek(syn::Token![,]) {
+            return Err(input.error("expected an argument, found an extra `,`"));
+        }
+        Ok(())
+    }
+
+    /// Names the outer attribute instead of the flattened one in the errors of a
+    /// `#[bae(flatten)]` field, whose arguments are written in the outer attribute.
+    pub fn flattened_error(err: syn::Error, flattened: &str, outer: &str) -> syn::Error {
+        let flattened = format!("`#[{}]`", flattened);
+        let outer = format!("`#[{}]`", outer);
+        err.into_iter()
+            .map(|err| syn::Error::new(err.span(), err.to_string().replace(&flattened, &outer)))
+            .reduce(|mut combined, err| {
+                combined.combine(err);
+                combined
+            })
+            .expect("syn::Error always holds at least one message")
+    }
+
+    /// Appends an argument no field matched, from its key up to the next top level `,`, to the
+    /// arguments left for a `#[bae(flatten)]` field.
+    pub fn capture_argument(
+        input: ParseStream,
+        key: &Key,
+        string_keys: bool,
+        captured: &mut TokenStream,
+    ) -> syn::Result<()> {
+        if string_keys {
+            syn::LitStr::new(&key.name, key.span).to_tokens(captured);
+        } else {
+            proc_macro2::Ident::new(&key.name, key.span).to_tokens(captured);
+        }
+        while !input.is_empty() && !input.peek(syn::Token![,]) {
+            captured.extend(std::iter::once(input.parse::<TokenTree>()?));
+        }
+        <syn::Token![,]>::default().to_tokens(captured);
+        Ok(())
+    }
+
+    /// Skips the rest of an argument, up to the next top level `,`.
+    pub fn skip_argument(input: ParseStream) -> syn::Result<()> {
+        while !input.is_empty() && !input.peek(syn::Token![,]) {
+            input.parse::<TokenTree>()?;
+        }
+        Ok(())
+    }
+
+    /// Parses a pattern which may have top level `|`, as in `Some(1) | None`, for `syn::Pat`
+    /// fields. This is syn's `multi_pat_with_leading_vert`, which syn 1 doesn't expose.
+    pub fn parse_multi_pat(input: ParseStream) -> syn::Result<syn::Pat> {
+        let is_vert = |input: ParseStream| {
+            input.peek(syn::Token![|])
+                && !input.peek(syn::Token![||])
+                && !input.peek(syn::Token![|=])
+        };
+
+        let leading_vert: Option<syn::Token![|]> = input.parse()?;
+        let pat: syn::Pat = input.parse()?;
+        if leading_vert.is_none() && !is_vert(input) {
+            return Ok(pat);
+        }
+
+        let mut cases = syn::punctuated::Punctuated::new();
+        cases.push_value(pat);
+        while is_vert(input) {
+            cases.push_punct(input.parse()?);
+            cases.push_value(input.parse()?);
+        }
+        Ok(syn::Pat::Or(syn::PatOr {
+            attrs: Vec::new(),
+            leading_vert,
+            cases,
+        }))
+    }
+
+    /// Parses a single outer attribute, such as `#[derive(Debug)]`.
+    pub fn parse_attribute(input: ParseStream) -> syn::Result<syn::Attribute> {
+        let mut attrs = syn::Attribute::parse_outer(input)?.into_iter();
+        match (attrs.next(), attrs.next()) {
+            (Some(attr), None) => Ok(attr),
+            (None, _) => Err(input.error("expected an attribute")),
+            (Some(_), Some(extra)) => Err(syn::Error::new_spanned(
+                extra,
+                "expected a single attribute",
+            )),
+        }
+    }
 }
@@ -0,0 +1,97 @@
+#!/usr/bin/env bash
+# Measures the code generated by `#[derive(FromAttributes)]` and how long it takes to compile.
+#
+# Usage: scripts/compile-time.sh [REV...]
+#
+# For each git revision (the working tree if none is given) a crate deriving 40 structs of 7
+# fields is built against that revision's better-bae. The script prints the size of its expanded
+# code and the best of three release and debug builds of the crate alone, its dependencies being
+# built beforehand. Expanding the code uses `-Zunpretty=expanded`, allowed on stable through
+# `RUSTC_BOOTSTRAP=1`.
+set -euo pipefail
+
+repo=$(cd "$(dirname "$0")/.." && pwd)
+work=$(mktemp -d)
+trap 'rm -rf "$work"; git -C "$repo" worktree prune' EXIT
+export CARGO_TARGET_DIR="$work/target"
+
+fixture() {
+    local dir=$1 better_bae=$2
+    mkdir -p "$dir/src"
+    cat >"$dir/Cargo.toml" <<TOML
+[package]
+name = "compile-time-fixture"
+version = "0.0.0"
+edition = "2018"
+publish = false
+
+[dependencies]
+better-bae = { path = "$better_bae" }
+syn = { version = "1", features = ["full", "extra-traits"] }
+proc-macro2 = "1"
+quote = "1"
+
+[workspace]
+TOML
+    echo "use better_bae::FromAttributes;" >"$dir/src/lib.rs"
+    for i in $(seq 0 39); do
+        cat >>"$dir/src/lib.rs" <<RUST
+
+#[derive(Debug, FromAttributes)]
+#[bae(to_tokens)]
+pub struct Attr$i {
+    pub name: syn::LitStr,
+    pub path: Option<syn::Path>,
+    pub count: Option<u32>,
+    pub flag: Option<()>,
+    pub list: Vec<syn::Ident>,
+    #[bae(default = "3")]
+    pub retries: u8,
+    pub ty: Option<syn::Type>,
+}
+RUST
+    done
+}
+
+# The best of three builds, in milliseconds
+best_build() {
+    local dir=$1 profile=$2 best=
+    cargo build --manifest-path "$dir/Cargo.toml" $profile -q
+    for _ in 1 2 3; do
+        touch "$dir/src/lib.rs"
+        local start end
+        start=$(date +%s%N)
+        cargo build --manifest-path "$dir/Cargo.toml" $profile -q
+        end=$(date +%s%N)
+        local ms=$(((end - start) / 1000000))
+        if [ -z "$best" ] || [ "$ms" -lt "$best" ]; then
+            best=$ms
+        fi
+    done
+    echo "$best"
+}
+
+measure() {
+    local name=$1 better_bae=$2 dir="$work/fixture-$1"
+    fixture "$dir" "$better_bae"
+    cargo build --manifest-path "$dir/Cargo.toml" -q
+    local expanded
+    expanded=$(
+        RUSTC_BOOTSTRAP=1 cargo rustc --manifest-path "$dir/Cargo.toml" -q --lib \
+            --profile=check -- -Zunpretty=expanded | wc -c
+    )
+    local debug release
+    debug=$(best_build "$dir" "")
+    release=$(best_build "$dir" --release)
+    printf '%-12s %10s bytes expanded %8s ms debug %8s ms release\n' \
+        "$name" "$expanded" "$debug" "$release"
+}
+
+if [ $# -eq 0 ]; then
+    measure "working-tree" "$repo/better-bae"
+else
+    for rev in "$@"; do
+        git -C "$repo" worktree add -q --detach "$work/$rev" "$rev"
+        measure "$rev" "$work/$rev/better-bae"
+    done
+fi